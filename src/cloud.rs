@@ -14,30 +14,71 @@
 
 //! Cloud API.
 
+#[cfg(any(
+    feature = "compute",
+    feature = "identity",
+    feature = "image",
+    feature = "network"
+))]
+use std::collections::HashMap;
 #[allow(unused_imports)]
 use std::io;
 use std::rc::Rc;
 
+#[cfg(feature = "network")]
+use fallible_iterator::FallibleIterator;
+use osauth::identity::{IdOrName, Password};
+use osauth::services::ServiceType;
+#[cfg(feature = "compute")]
+use osauth::services::COMPUTE;
+#[cfg(feature = "image")]
+use osauth::services::IMAGE;
+#[cfg(feature = "network")]
+use osauth::services::NETWORK;
+#[cfg(feature = "object-storage")]
+use osauth::services::OBJECT_STORAGE;
 use osauth::sync::SyncSession;
 use osauth::{AuthType, Session};
 
+#[cfg(any(
+    feature = "compute",
+    feature = "identity",
+    feature = "image",
+    feature = "network"
+))]
+use super::common::{fetch_versions, ServiceVersion};
 #[allow(unused_imports)]
-use super::common::{ContainerRef, FlavorRef, NetworkRef};
+use super::common::{
+    ContainerRef, DeletionWaiter, FlavorRef, IkePolicyRef, IpsecPolicyRef, NetworkRef, PortRef,
+    RouterRef, VpnServiceRef,
+};
 #[cfg(feature = "compute")]
 use super::compute::{
-    Flavor, FlavorQuery, FlavorSummary, KeyPair, KeyPairQuery, NewKeyPair, NewServer, Server,
-    ServerQuery, ServerSummary,
+    Flavor, FlavorQuery, FlavorSummary, KeyPair, KeyPairQuery, NewFlavor, NewKeyPair, NewServer,
+    Server, ServerGroup, ServerGroupQuery, ServerQuery, ServerSummary,
 };
+#[cfg(feature = "identity")]
+use super::identity::{self, ServiceEntry};
 #[cfg(feature = "image")]
 use super::image::{Image, ImageQuery};
 #[cfg(feature = "network")]
 use super::network::{
-    FloatingIp, FloatingIpQuery, Network, NetworkQuery, NewFloatingIp, NewNetwork, NewPort,
-    NewRouter, NewSubnet, Port, PortQuery, Router, RouterQuery, Subnet, SubnetQuery,
+    AddressScope, AddressScopeQuery, BgpPeer, BgpPeerQuery, BgpSpeaker, BgpSpeakerQuery,
+    FloatingIp, FloatingIpQuery, FlowClassifier, FlowClassifierQuery, IkePolicy, IkePolicyQuery,
+    IpVersion, IpsecPolicy, IpsecPolicyQuery, Network, NetworkAgent, NetworkAgentQuery,
+    NetworkQuery, NetworkSegment, NetworkSegmentQuery, NewAddressScope, NewBgpPeer, NewBgpSpeaker,
+    NewFloatingIp, NewFlowClassifier, NewIkePolicy, NewIpsecPolicy, NewNetwork, NewPort,
+    NewPortChain, NewPortPair, NewPortPairGroup, NewRouter, NewSiteConnection, NewSubnet,
+    NewSubnetPool, NewVpnService, Port, PortChain, PortChainQuery, PortPair, PortPairGroup,
+    PortPairGroupQuery, PortPairQuery, PortQuery, Router, RouterQuery, SiteConnection,
+    SiteConnectionQuery, Subnet, SubnetPool, SubnetPoolQuery, SubnetQuery, VpnService,
+    VpnServiceQuery,
 };
 #[cfg(feature = "object-storage")]
-use super::object_storage::{Container, ContainerQuery, NewObject, Object, ObjectQuery};
-use super::{EndpointFilters, InterfaceType, Result};
+use super::object_storage::{Account, Container, ContainerQuery, NewObject, Object, ObjectQuery};
+#[cfg(feature = "network")]
+use super::Error;
+use super::{EndpointFilters, ErrorKind, InterfaceType, Result};
 
 /// OpenStack cloud API.
 ///
@@ -70,10 +111,35 @@ impl Cloud {
     /// }
     /// ```
     ///
+    /// # Connection pooling
+    ///
+    /// HTTP connections are kept alive and reused by the `reqwest::Client`
+    /// owned by the authentication plugin. The pool is per cloud object (and
+    /// its clones), not global. To tune it, create the plugin with a
+    /// pre-configured client:
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    ///
+    /// fn cloud() -> openstack::Result<openstack::Cloud> {
+    ///     let client = reqwest::Client::builder()
+    ///         .pool_max_idle_per_host(32)
+    ///         .pool_idle_timeout(Duration::from_secs(90))
+    ///         .build()
+    ///         .expect("Cannot create an HTTP client");
+    ///     let auth = openstack::auth::Password::new_with_client(
+    ///             "https://cloud.example.com", client,
+    ///             "user1", "pa$$word", "Default")
+    ///         .expect("Invalid authentication URL");
+    ///     Ok(openstack::Cloud::new(auth))
+    /// }
+    /// ```
+    ///
     /// # See Also
     ///
     /// * [from_config](#method.from_config) to create a Cloud from clouds.yaml
     /// * [from_env](#method.from_env) to create a Cloud from environment variables
+    /// * [from_password](#method.from_password) to create a Cloud from user credentials
     pub fn new<Auth: AuthType + 'static>(auth_type: Auth) -> Cloud {
         Cloud {
             session: Rc::new(SyncSession::new(Session::new(auth_type))),
@@ -97,6 +163,13 @@ impl Cloud {
 
     /// Create a new cloud object from environment variables.
     ///
+    /// Understands the variables exported by a typical `openrc` file:
+    /// `OS_AUTH_URL`, `OS_USERNAME`, `OS_PASSWORD`, `OS_USER_DOMAIN_NAME`,
+    /// `OS_PROJECT_NAME` (or `OS_PROJECT_ID`) and `OS_PROJECT_DOMAIN_NAME`.
+    /// `OS_REGION_NAME`, `OS_INTERFACE` and `OS_CACERT` are honored if set,
+    /// and `OS_CLOUD` switches to loading from `clouds.yaml`. A missing
+    /// required variable results in an `InvalidInput` error naming it.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -110,6 +183,41 @@ impl Cloud {
         })
     }
 
+    /// Create a new cloud object using password authentication.
+    ///
+    /// The resulting session is scoped to the given project. This is a
+    /// shortcut for creating an `auth::Password` and passing it to
+    /// [new](#method.new).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # fn cloud_from_password() -> openstack::Result<()> {
+    /// let os = openstack::Cloud::from_password(
+    ///     "https://cloud.example.com",
+    ///     "user1",
+    ///     "pa$$word",
+    ///     "Default",
+    ///     "project1",
+    ///     "Default",
+    /// )?;
+    /// # Ok(()) }
+    /// ```
+    pub fn from_password(
+        auth_url: &str,
+        username: &str,
+        password: &str,
+        user_domain: &str,
+        project_name: &str,
+        project_domain: &str,
+    ) -> Result<Cloud> {
+        let auth = Password::new(auth_url, username, password, user_domain)?.with_project_scope(
+            IdOrName::Name(project_name.to_string()),
+            IdOrName::Name(project_domain.to_string()),
+        );
+        Ok(Cloud::new(auth))
+    }
+
     /// Endpoint filters for this cloud.
     #[inline]
     pub fn endpoint_filters(&self) -> &EndpointFilters {
@@ -172,6 +280,179 @@ impl Cloud {
         Rc::make_mut(&mut self.session).refresh()
     }
 
+    /// Session used by this cloud.
+    ///
+    /// Can be used to call APIs that this crate does not cover yet. Requests
+    /// built with `Session::request` can be customized before being sent, and
+    /// `Session::send_checked` sends them with the same authentication and
+    /// error handling as the rest of the crate.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use osauth::services::COMPUTE;
+    /// use reqwest::Method;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let session = os.session();
+    /// let response = session
+    ///     .request(COMPUTE, Method::GET, &["os-hypervisors"], None)
+    ///     .and_then(|builder| session.send_checked(builder.header("X-Example", "yes")))
+    ///     .expect("Unable to list hypervisors");
+    /// println!("Status: {}", response.status());
+    /// ```
+    #[inline]
+    pub fn session(&self) -> &SyncSession {
+        &self.session
+    }
+
+    /// Fetch the service catalog and endpoints of all enabled services.
+    ///
+    /// Endpoints are normally discovered lazily on the first request to each
+    /// service. Calling this method upfront makes the latency of subsequent
+    /// calls predictable and fails early on authentication or catalog errors.
+    ///
+    /// Services missing from the catalog are skipped, since most clouds do
+    /// not provide every service enabled in this crate. Use `require_service`
+    /// to fail early if a service your application needs is missing.
+    pub fn warm_up(&self) -> Result<()> {
+        #[cfg(feature = "compute")]
+        self.warm_up_service(COMPUTE)?;
+        #[cfg(feature = "image")]
+        self.warm_up_service(IMAGE)?;
+        #[cfg(feature = "network")]
+        self.warm_up_service(NETWORK)?;
+        #[cfg(feature = "object-storage")]
+        self.warm_up_service(OBJECT_STORAGE)?;
+        Ok(())
+    }
+
+    /// Ensure that the service is present in the catalog.
+    ///
+    /// Fails with `EndpointNotFound` if it is not. The endpoint is cached, so
+    /// this check is cheap after `warm_up`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use osauth::services::COMPUTE;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// os.warm_up().expect("Unable to fetch the service catalog");
+    /// os.require_service(COMPUTE).expect("Compute service is not available");
+    /// ```
+    pub fn require_service<Srv: ServiceType + Send>(&self, service: Srv) -> Result<()> {
+        let _ = self.session.get_endpoint(service, Vec::<&str>::new())?;
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    fn warm_up_service<Srv: ServiceType + Send>(&self, service: Srv) -> Result<()> {
+        let catalog_type = service.catalog_type();
+        match self.require_service(service) {
+            Err(err) if err.kind() == ErrorKind::EndpointNotFound => {
+                debug!("Service {} is not in the catalog, skipping", catalog_type);
+                Ok(())
+            }
+            other => other,
+        }
+    }
+
+    /// Fetch the API versions supported by all enabled services.
+    ///
+    /// The result maps a service type (e.g. `compute`) to its versions.
+    /// Services that are missing from the catalog are not included. The object
+    /// storage service does not support version discovery and is never
+    /// included either. Services whose versions cannot be fetched are skipped
+    /// with a warning.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// for (service, versions) in os.get_all_versions() {
+    ///     for version in versions {
+    ///         println!("{}: {} ({})", service, version.id, version.status);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(any(
+        feature = "compute",
+        feature = "identity",
+        feature = "image",
+        feature = "network"
+    ))]
+    pub fn get_all_versions(&self) -> HashMap<String, Vec<ServiceVersion>> {
+        let mut result = HashMap::new();
+        #[cfg(feature = "compute")]
+        match fetch_versions(&self.session, COMPUTE) {
+            Ok(Some(versions)) => {
+                let _ = result.insert(COMPUTE.catalog_type().to_string(), versions);
+            }
+            Ok(None) => {}
+            Err(err) => warn!("Unable to fetch versions of the compute service: {}", err),
+        }
+        #[cfg(feature = "identity")]
+        match fetch_versions(&self.session, identity::IDENTITY) {
+            Ok(Some(versions)) => {
+                let _ = result.insert(identity::IDENTITY.catalog_type().to_string(), versions);
+            }
+            Ok(None) => {}
+            Err(err) => warn!("Unable to fetch versions of the identity service: {}", err),
+        }
+        #[cfg(feature = "image")]
+        match fetch_versions(&self.session, IMAGE) {
+            Ok(Some(versions)) => {
+                let _ = result.insert(IMAGE.catalog_type().to_string(), versions);
+            }
+            Ok(None) => {}
+            Err(err) => warn!("Unable to fetch versions of the image service: {}", err),
+        }
+        #[cfg(feature = "network")]
+        match fetch_versions(&self.session, NETWORK) {
+            Ok(Some(versions)) => {
+                let _ = result.insert(NETWORK.catalog_type().to_string(), versions);
+            }
+            Ok(None) => {}
+            Err(err) => warn!("Unable to fetch versions of the network service: {}", err),
+        }
+        result
+    }
+
+    /// Fetch several servers by their IDs or names concurrently.
+    ///
+    /// Unlike calling `get_server` in a loop, the servers are fetched in
+    /// parallel. The results are returned in the same order as `ids`. A
+    /// failure to fetch one server does not affect the others.
+    ///
+    /// Every server is fetched in a separate OS thread, up to 16 at a time.
+    /// Each thread uses its own copy of the session with a separate async
+    /// runtime, so this is only worth it for more than a few servers. The
+    /// flavors of the servers are fetched afterwards, one request for each
+    /// distinct flavor.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// for server in os.batch_get_servers(&["server-1", "server-2"]) {
+    ///     match server {
+    ///         Ok(server) => println!("{} is {}", server.name(), server.status()),
+    ///         Err(err) => println!("Failed to fetch a server: {}", err),
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn batch_get_servers<Id: AsRef<str>>(&self, ids: &[Id]) -> Vec<Result<Server>> {
+        Server::load_many(self.session.clone(), ids)
+    }
+
     /// Create a new container.
     ///
     /// If the container already exists, this call returns successfully.
@@ -191,6 +472,54 @@ impl Cloud {
         Object::create(self.session.clone(), container, name, body)
     }
 
+    /// Delete a server by its ID or name.
+    ///
+    /// A shortcut for `get_server(id_or_name)?.delete()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    /// use waiter::Waiter;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// os.delete_server("example-server")
+    ///     .expect("Unable to delete server")
+    ///     .wait()
+    ///     .expect("Server was not deleted");
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn delete_server<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<DeletionWaiter<Server>> {
+        self.get_server(id_or_name)?.delete()
+    }
+
+    /// Build a query against address scope list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_address_scopes(&self) -> AddressScopeQuery {
+        AddressScopeQuery::new(self.session.clone())
+    }
+
+    /// Build a query against BGP peer list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_bgp_peers(&self) -> BgpPeerQuery {
+        BgpPeerQuery::new(self.session.clone())
+    }
+
+    /// Build a query against BGP speaker list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_bgp_speakers(&self) -> BgpSpeakerQuery {
+        BgpSpeakerQuery::new(self.session.clone())
+    }
+
     /// Build a query against container list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -213,6 +542,15 @@ impl Cloud {
         ObjectQuery::new(self.session.clone(), container)
     }
 
+    /// Build a query against the list of external networks.
+    ///
+    /// The returned object is a builder that can be used to further
+    /// narrow down the query.
+    #[cfg(feature = "network")]
+    pub fn find_external_networks(&self) -> NetworkQuery {
+        NetworkQuery::external_networks(self.session.clone())
+    }
+
     /// Build a query against flavor list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -231,6 +569,24 @@ impl Cloud {
         FloatingIpQuery::new(self.session.clone())
     }
 
+    /// Build a query against flow classifier list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_flow_classifiers(&self) -> FlowClassifierQuery {
+        FlowClassifierQuery::new(self.session.clone())
+    }
+
+    /// Build a query against IKE policy list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_ike_policies(&self) -> IkePolicyQuery {
+        IkePolicyQuery::new(self.session.clone())
+    }
+
     /// Build a query against image list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -240,6 +596,15 @@ impl Cloud {
         ImageQuery::new(self.session.clone())
     }
 
+    /// Build a query against IPsec policy list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_ipsec_policies(&self) -> IpsecPolicyQuery {
+        IpsecPolicyQuery::new(self.session.clone())
+    }
+
     /// Build a query against key pairs list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -249,6 +614,24 @@ impl Cloud {
         KeyPairQuery::new(self.session.clone())
     }
 
+    /// Build a query against network agent list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_network_agents(&self) -> NetworkAgentQuery {
+        NetworkAgentQuery::new(self.session.clone())
+    }
+
+    /// Build a query against network segment list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_network_segments(&self) -> NetworkSegmentQuery {
+        NetworkSegmentQuery::new(self.session.clone())
+    }
+
     /// Build a query against network list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -267,6 +650,33 @@ impl Cloud {
         PortQuery::new(self.session.clone())
     }
 
+    /// Build a query against port chain list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_port_chains(&self) -> PortChainQuery {
+        PortChainQuery::new(self.session.clone())
+    }
+
+    /// Build a query against port pair group list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_port_pair_groups(&self) -> PortPairGroupQuery {
+        PortPairGroupQuery::new(self.session.clone())
+    }
+
+    /// Build a query against port pair list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_port_pairs(&self) -> PortPairQuery {
+        PortPairQuery::new(self.session.clone())
+    }
+
     /// Build a query against router list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -299,6 +709,24 @@ impl Cloud {
         ServerQuery::new(self.session.clone())
     }
 
+    /// Build a query against server group list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "compute")]
+    pub fn find_server_groups(&self) -> ServerGroupQuery {
+        ServerGroupQuery::new(self.session.clone())
+    }
+
+    /// Build a query against IPsec site connection list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_site_connections(&self) -> SiteConnectionQuery {
+        SiteConnectionQuery::new(self.session.clone())
+    }
+
     /// Build a query against subnet list.
     ///
     /// The returned object is a builder that should be used to construct
@@ -308,7 +736,25 @@ impl Cloud {
         SubnetQuery::new(self.session.clone())
     }
 
-    /// Get object container metadata by its name.
+    /// Build a query against subnet pool list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_subnet_pools(&self) -> SubnetPoolQuery {
+        SubnetPoolQuery::new(self.session.clone())
+    }
+
+    /// Build a query against VPN service list.
+    ///
+    /// The returned object is a builder that should be used to construct
+    /// the query.
+    #[cfg(feature = "network")]
+    pub fn find_vpn_services(&self) -> VpnServiceQuery {
+        VpnServiceQuery::new(self.session.clone())
+    }
+
+    /// Find an address scope by its name or ID.
     ///
     /// # Example
     ///
@@ -316,14 +762,15 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let ctr = os.get_container("www").expect("Unable to get a container");
+    /// let address_scope = os.get_address_scope("private-scope")
+    ///     .expect("Unable to get an address scope");
     /// ```
-    #[cfg(feature = "object-storage")]
-    pub fn get_container<Id: AsRef<str>>(&self, name: Id) -> Result<Container> {
-        Container::load(self.session.clone(), name)
+    #[cfg(feature = "network")]
+    pub fn get_address_scope<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<AddressScope> {
+        AddressScope::load(self.session.clone(), id_or_name)
     }
 
-    /// Get object metadata by its name.
+    /// Find a BGP peer by its name or ID.
     ///
     /// # Example
     ///
@@ -331,18 +778,15 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let obj = os.get_object("www", "/foo/bar").expect("Unable to get an object");
+    /// let bgp_peer = os.get_bgp_peer("bgp-peer")
+    ///     .expect("Unable to get a BGP peer");
     /// ```
-    #[cfg(feature = "object-storage")]
-    pub fn get_object<C, Id>(&self, container: C, name: Id) -> Result<Object>
-    where
-        C: Into<ContainerRef>,
-        Id: AsRef<str>,
-    {
-        Object::load(self.session.clone(), container, name)
+    #[cfg(feature = "network")]
+    pub fn get_bgp_peer<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<BgpPeer> {
+        BgpPeer::load(self.session.clone(), id_or_name)
     }
 
-    /// Find a flavor by its name or ID.
+    /// Find a BGP speaker by its name or ID.
     ///
     /// # Example
     ///
@@ -350,14 +794,15 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server = os.get_flavor("m1.medium").expect("Unable to get a flavor");
+    /// let bgp_speaker = os.get_bgp_speaker("bgp-speaker")
+    ///     .expect("Unable to get a BGP speaker");
     /// ```
-    #[cfg(feature = "compute")]
-    pub fn get_flavor<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Flavor> {
-        Flavor::load(self.session.clone(), id_or_name)
+    #[cfg(feature = "network")]
+    pub fn get_bgp_speaker<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<BgpSpeaker> {
+        BgpSpeaker::load(self.session.clone(), id_or_name)
     }
 
-    /// Find a floating IP by its ID.
+    /// Get object container metadata by its name.
     ///
     /// # Example
     ///
@@ -365,15 +810,14 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server = os.get_floating_ip("031e08c7-2ca7-4c0b-9923-030c8d946ba4")
-    ///     .expect("Unable to get a floating IP");
+    /// let ctr = os.get_container("www").expect("Unable to get a container");
     /// ```
-    #[cfg(feature = "network")]
-    pub fn get_floating_ip<Id: AsRef<str>>(&self, id: Id) -> Result<FloatingIp> {
-        FloatingIp::load(self.session.clone(), id)
+    #[cfg(feature = "object-storage")]
+    pub fn get_container<Id: AsRef<str>>(&self, name: Id) -> Result<Container> {
+        Container::load(self.session.clone(), name)
     }
 
-    /// Find an image by its name or ID.
+    /// Get the object storage account of the current project.
     ///
     /// # Example
     ///
@@ -381,14 +825,15 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server = os.get_image("centos7").expect("Unable to get a image");
+    /// let mut account = os.get_object_storage_account().expect("Unable to get the account");
+    /// account.set_temp_url_key("secret").expect("Unable to set the temporary URL key");
     /// ```
-    #[cfg(feature = "image")]
-    pub fn get_image<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Image> {
-        Image::new(self.session.clone(), id_or_name)
+    #[cfg(feature = "object-storage")]
+    pub fn get_object_storage_account(&self) -> Result<Account> {
+        Account::load(self.session.clone())
     }
 
-    /// Find a key pair by its name or ID.
+    /// Get object metadata by its name.
     ///
     /// # Example
     ///
@@ -396,14 +841,21 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server = os.get_keypair("default").expect("Unable to get a key pair");
+    /// let obj = os.get_object("www", "/foo/bar").expect("Unable to get an object");
     /// ```
-    #[cfg(feature = "compute")]
-    pub fn get_keypair<Id: AsRef<str>>(&self, name: Id) -> Result<KeyPair> {
-        KeyPair::new(self.session.clone(), name)
+    #[cfg(feature = "object-storage")]
+    pub fn get_object<C, Id>(&self, container: C, name: Id) -> Result<Object>
+    where
+        C: Into<ContainerRef>,
+        Id: AsRef<str>,
+    {
+        Object::load(self.session.clone(), container, name)
     }
 
-    /// Find an network by its name or ID.
+    /// Find an external network.
+    ///
+    /// If multiple external networks exist, the first one is returned. Fails
+    /// with `ResourceNotFound` if there are no external networks.
     ///
     /// # Example
     ///
@@ -411,14 +863,17 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server = os.get_network("centos7").expect("Unable to get a network");
+    /// let network = os.get_external_network().expect("Unable to find an external network");
     /// ```
     #[cfg(feature = "network")]
-    pub fn get_network<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Network> {
-        Network::load(self.session.clone(), id_or_name)
+    pub fn get_external_network(&self) -> Result<Network> {
+        self.find_external_networks()
+            .into_iter()
+            .next()?
+            .ok_or_else(|| Error::new(ErrorKind::ResourceNotFound, "No external networks found"))
     }
 
-    /// Find an port by its name or ID.
+    /// Find a flavor by its name or ID.
     ///
     /// # Example
     ///
@@ -426,15 +881,606 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server = os.get_port("4d9c1710-fa02-49f9-8218-291024ef4140")
-    ///     .expect("Unable to get a port");
+    /// let server = os.get_flavor("m1.medium").expect("Unable to get a flavor");
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn get_flavor<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Flavor> {
+        Flavor::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a floating IP by its ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server = os.get_floating_ip("031e08c7-2ca7-4c0b-9923-030c8d946ba4")
+    ///     .expect("Unable to get a floating IP");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_floating_ip<Id: AsRef<str>>(&self, id: Id) -> Result<FloatingIp> {
+        FloatingIp::load(self.session.clone(), id)
+    }
+
+    /// Find a flow classifier by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let flow_classifier = os.get_flow_classifier("flow-classifier")
+    ///     .expect("Unable to get a flow classifier");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_flow_classifier<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<FlowClassifier> {
+        FlowClassifier::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a floating IP that is not associated with any port.
+    ///
+    /// A new floating IP is allocated from the given network if there are no
+    /// free floating IPs in it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let floating_ip = os.get_free_floating_ip("public")
+    ///     .expect("Unable to get a floating IP");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_free_floating_ip<N>(&self, floating_network: N) -> Result<FloatingIp>
+    where
+        N: Into<NetworkRef>,
+    {
+        let floating_network = floating_network.into();
+        match FloatingIp::find_free(self.session.clone(), floating_network.clone())? {
+            Some(floating_ip) => Ok(floating_ip),
+            None => self.new_floating_ip(floating_network).create(),
+        }
+    }
+
+    /// Find an IKE policy by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let ike_policy = os.get_ike_policy("ike-policy")
+    ///     .expect("Unable to get an IKE policy");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_ike_policy<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<IkePolicy> {
+        IkePolicy::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find an image by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server = os.get_image("centos7").expect("Unable to get a image");
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn get_image<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Image> {
+        Image::new(self.session.clone(), id_or_name)
+    }
+
+    /// Find an IPsec policy by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let ipsec_policy = os.get_ipsec_policy("ipsec-policy")
+    ///     .expect("Unable to get an IPsec policy");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_ipsec_policy<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<IpsecPolicy> {
+        IpsecPolicy::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a key pair by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server = os.get_keypair("default").expect("Unable to get a key pair");
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn get_keypair<Id: AsRef<str>>(&self, name: Id) -> Result<KeyPair> {
+        KeyPair::new(self.session.clone(), name)
+    }
+
+    /// Find an network by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server = os.get_network("centos7").expect("Unable to get a network");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_network<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Network> {
+        Network::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a network agent by its ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let agent = os.get_network_agent("8f2bc8e6-e4a8-4a41-a0ec-d7e4ad1a6b1a")
+    ///     .expect("Unable to get a network agent");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_network_agent<Id: AsRef<str>>(&self, id: Id) -> Result<NetworkAgent> {
+        NetworkAgent::load(self.session.clone(), id)
+    }
+
+    /// Find a network segment by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let segment = os.get_network_segment("segment-1")
+    ///     .expect("Unable to get a network segment");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_network_segment<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<NetworkSegment> {
+        NetworkSegment::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find an port by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server = os.get_port("4d9c1710-fa02-49f9-8218-291024ef4140")
+    ///     .expect("Unable to get a port");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_port<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Port> {
+        Port::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a port chain by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let port_chain = os.get_port_chain("port-chain")
+    ///     .expect("Unable to get a port chain");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_port_chain<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<PortChain> {
+        PortChain::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a port pair group by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let port_pair_group = os.get_port_pair_group("port-pair-group")
+    ///     .expect("Unable to get a port pair group");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_port_pair_group<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<PortPairGroup> {
+        PortPairGroup::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a port pair by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let port_pair = os.get_port_pair("port-pair")
+    ///     .expect("Unable to get a port pair");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_port_pair<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<PortPair> {
+        PortPair::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a router by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let router = os.get_router("router_name").expect("Unable to get a router");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_router<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Router> {
+        Router::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a server by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server = os.get_server("8a1c355b-2e1e-440a-8aa8-f272df72bc32")
+    ///     .expect("Unable to get a server");
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn get_server<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Server> {
+        Server::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a server group by its ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let group = os.get_server_group("8a1c355b-2e1e-440a-8aa8-f272df72bc32")
+    ///     .expect("Unable to get a server group");
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn get_server_group<Id: AsRef<str>>(&self, id: Id) -> Result<ServerGroup> {
+        ServerGroup::load(self.session.clone(), id)
+    }
+
+    /// Find an IPsec site connection by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let site_connection = os.get_site_connection("site-connection")
+    ///     .expect("Unable to get an IPsec site connection");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_site_connection<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<SiteConnection> {
+        SiteConnection::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find an subnet by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server = os.get_subnet("private-subnet")
+    ///     .expect("Unable to get a subnet");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_subnet<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Subnet> {
+        Subnet::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a subnet pool by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let subnet_pool = os.get_subnet_pool("private-pool")
+    ///     .expect("Unable to get a subnet pool");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_subnet_pool<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<SubnetPool> {
+        SubnetPool::load(self.session.clone(), id_or_name)
+    }
+
+    /// Find a VPN service by its name or ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let vpn_service = os.get_vpn_service("vpn-service")
+    ///     .expect("Unable to get a VPN service");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn get_vpn_service<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<VpnService> {
+        VpnService::load(self.session.clone(), id_or_name)
+    }
+
+    /// List all address scopes.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_address_scopes](#method.find_address_scopes) call to limit the number of
+    /// address scopes to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let address_scopes = os.list_address_scopes().expect("Unable to fetch address scopes");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn list_address_scopes(&self) -> Result<Vec<AddressScope>> {
+        self.find_address_scopes().all()
+    }
+
+    /// List all BGP peers.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_bgp_peers](#method.find_bgp_peers) call to limit the number of
+    /// BGP peers to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let bgp_peers = os.list_bgp_peers().expect("Unable to fetch BGP peers");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn list_bgp_peers(&self) -> Result<Vec<BgpPeer>> {
+        self.find_bgp_peers().all()
+    }
+
+    /// List all BGP speakers.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_bgp_speakers](#method.find_bgp_speakers) call to limit the number of
+    /// BGP speakers to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let bgp_speakers = os.list_bgp_speakers().expect("Unable to fetch BGP speakers");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn list_bgp_speakers(&self) -> Result<Vec<BgpSpeaker>> {
+        self.find_bgp_speakers().all()
+    }
+
+    /// List all containers.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_containers](#method.find_containers) call to limit the number of
+    /// containers to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server_list = os.list_containers().expect("Unable to fetch containers");
+    /// ```
+    #[cfg(feature = "object-storage")]
+    pub fn list_containers(&self) -> Result<Vec<Container>> {
+        self.find_containers().all()
+    }
+
+    /// List all objects.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_objects](#method.find_objects) call to limit the number of
+    /// objects to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server_list = os.list_objects("www").expect("Unable to fetch objects");
+    /// ```
+    #[cfg(feature = "object-storage")]
+    pub fn list_objects<C>(&self, container: C) -> Result<Vec<Object>>
+    where
+        C: Into<ContainerRef>,
+    {
+        self.find_objects(container).all()
+    }
+
+    /// List all flavors.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_flavors](#method.find_flavors) call to limit the number of
+    /// flavors to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server_list = os.list_flavors().expect("Unable to fetch flavors");
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn list_flavors(&self) -> Result<Vec<FlavorSummary>> {
+        self.find_flavors().all()
+    }
+
+    /// List all floating IPs
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_floating_ips](#method.find_floating_ips) call to limit the number of
+    /// networks to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server_list = os.list_floating_ips().expect("Unable to fetch floating IPs");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn list_floating_ips(&self) -> Result<Vec<FloatingIp>> {
+        self.find_floating_ips().all()
+    }
+
+    /// List all flow classifiers.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_flow_classifiers](#method.find_flow_classifiers) call to limit the number of
+    /// flow classifiers to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let flow_classifiers = os.list_flow_classifiers().expect("Unable to fetch flow classifiers");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn list_flow_classifiers(&self) -> Result<Vec<FlowClassifier>> {
+        self.find_flow_classifiers().all()
+    }
+
+    /// List all IKE policies.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_ike_policies](#method.find_ike_policies) call to limit the number of
+    /// IKE policies to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let ike_policies = os.list_ike_policies().expect("Unable to fetch IKE policies");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn list_ike_policies(&self) -> Result<Vec<IkePolicy>> {
+        self.find_ike_policies().all()
+    }
+
+    /// List all images.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_images](#method.find_images) call to limit the number of
+    /// images to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server_list = os.list_images().expect("Unable to fetch images");
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn list_images(&self) -> Result<Vec<Image>> {
+        self.find_images().all()
+    }
+
+    /// List all IPsec policies.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_ipsec_policies](#method.find_ipsec_policies) call to limit the number of
+    /// IPsec policies to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let ipsec_policies = os.list_ipsec_policies().expect("Unable to fetch IPsec policies");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn list_ipsec_policies(&self) -> Result<Vec<IpsecPolicy>> {
+        self.find_ipsec_policies().all()
+    }
+
+    /// List all key pairs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let result = os.list_keypairs().expect("Unable to fetch key pairs");
+    /// ```
+    #[cfg(feature = "compute")]
+    pub fn list_keypairs(&self) -> Result<Vec<KeyPair>> {
+        self.find_keypairs().all()
+    }
+
+    /// List all networks.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_networks](#method.find_networks) call to limit the number of
+    /// networks to receive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let server_list = os.list_networks().expect("Unable to fetch networks");
     /// ```
     #[cfg(feature = "network")]
-    pub fn get_port<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Port> {
-        Port::load(self.session.clone(), id_or_name)
+    pub fn list_networks(&self) -> Result<Vec<Network>> {
+        self.find_networks().all()
     }
 
-    /// Find a router by its name or ID.
+    /// List all network agents.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_network_agents](#method.find_network_agents) call to limit the number of
+    /// agents to receive.
     ///
     /// # Example
     ///
@@ -442,14 +1488,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let router = os.get_router("router_name").expect("Unable to get a router");
+    /// let agents = os.list_network_agents().expect("Unable to fetch network agents");
     /// ```
     #[cfg(feature = "network")]
-    pub fn get_router<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Router> {
-        Router::load(self.session.clone(), id_or_name)
+    pub fn list_network_agents(&self) -> Result<Vec<NetworkAgent>> {
+        self.find_network_agents().all()
     }
 
-    /// Find a server by its name or ID.
+    /// List all network segments.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_network_segments](#method.find_network_segments) call to limit the number of
+    /// segments to receive.
     ///
     /// # Example
     ///
@@ -457,15 +1507,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server = os.get_server("8a1c355b-2e1e-440a-8aa8-f272df72bc32")
-    ///     .expect("Unable to get a server");
+    /// let segments = os.list_network_segments().expect("Unable to fetch network segments");
     /// ```
-    #[cfg(feature = "compute")]
-    pub fn get_server<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Server> {
-        Server::load(self.session.clone(), id_or_name)
+    #[cfg(feature = "network")]
+    pub fn list_network_segments(&self) -> Result<Vec<NetworkSegment>> {
+        self.find_network_segments().all()
     }
 
-    /// Find an subnet by its name or ID.
+    /// List all ports.
+    ///
+    /// This call can yield a lot of results, use the
+    /// [find_ports](#method.find_ports) call to limit the number of
+    /// ports to receive.
     ///
     /// # Example
     ///
@@ -473,19 +1526,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server = os.get_subnet("private-subnet")
-    ///     .expect("Unable to get a subnet");
+    /// let server_list = os.list_ports().expect("Unable to fetch ports");
     /// ```
     #[cfg(feature = "network")]
-    pub fn get_subnet<Id: AsRef<str>>(&self, id_or_name: Id) -> Result<Subnet> {
-        Subnet::load(self.session.clone(), id_or_name)
+    pub fn list_ports(&self) -> Result<Vec<Port>> {
+        self.find_ports().all()
     }
 
-    /// List all containers.
+    /// List all port chains.
     ///
     /// This call can yield a lot of results, use the
-    /// [find_containers](#method.find_containers) call to limit the number of
-    /// containers to receive.
+    /// [find_port_chains](#method.find_port_chains) call to limit the number of
+    /// port chains to receive.
     ///
     /// # Example
     ///
@@ -493,18 +1545,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server_list = os.list_containers().expect("Unable to fetch containers");
+    /// let port_chains = os.list_port_chains().expect("Unable to fetch port chains");
     /// ```
-    #[cfg(feature = "object-storage")]
-    pub fn list_containers(&self) -> Result<Vec<Container>> {
-        self.find_containers().all()
+    #[cfg(feature = "network")]
+    pub fn list_port_chains(&self) -> Result<Vec<PortChain>> {
+        self.find_port_chains().all()
     }
 
-    /// List all objects.
+    /// List all port pair groups.
     ///
     /// This call can yield a lot of results, use the
-    /// [find_objects](#method.find_objects) call to limit the number of
-    /// objects to receive.
+    /// [find_port_pair_groups](#method.find_port_pair_groups) call to limit the number of
+    /// port pair groups to receive.
     ///
     /// # Example
     ///
@@ -512,21 +1564,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server_list = os.list_objects("www").expect("Unable to fetch objects");
+    /// let port_pair_groups = os.list_port_pair_groups().expect("Unable to fetch port pair groups");
     /// ```
-    #[cfg(feature = "object-storage")]
-    pub fn list_objects<C>(&self, container: C) -> Result<Vec<Object>>
-    where
-        C: Into<ContainerRef>,
-    {
-        self.find_objects(container).all()
+    #[cfg(feature = "network")]
+    pub fn list_port_pair_groups(&self) -> Result<Vec<PortPairGroup>> {
+        self.find_port_pair_groups().all()
     }
 
-    /// List all flavors.
+    /// List all port pairs.
     ///
     /// This call can yield a lot of results, use the
-    /// [find_flavors](#method.find_flavors) call to limit the number of
-    /// flavors to receive.
+    /// [find_port_pairs](#method.find_port_pairs) call to limit the number of
+    /// port pairs to receive.
     ///
     /// # Example
     ///
@@ -534,18 +1583,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server_list = os.list_flavors().expect("Unable to fetch flavors");
+    /// let port_pairs = os.list_port_pairs().expect("Unable to fetch port pairs");
     /// ```
-    #[cfg(feature = "compute")]
-    pub fn list_flavors(&self) -> Result<Vec<FlavorSummary>> {
-        self.find_flavors().all()
+    #[cfg(feature = "network")]
+    pub fn list_port_pairs(&self) -> Result<Vec<PortPair>> {
+        self.find_port_pairs().all()
     }
 
-    /// List all floating IPs
+    /// List all routers.
     ///
     /// This call can yield a lot of results, use the
-    /// [find_floating_ips](#method.find_floating_ips) call to limit the number of
-    /// networks to receive.
+    /// [find_routers](#method.find_routers) call to limit the number of
+    /// routers to receive.
     ///
     /// # Example
     ///
@@ -553,18 +1602,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server_list = os.list_floating_ips().expect("Unable to fetch floating IPs");
+    /// let router_list = os.list_routers().expect("Unable to fetch routers");
     /// ```
     #[cfg(feature = "network")]
-    pub fn list_floating_ips(&self) -> Result<Vec<FloatingIp>> {
-        self.find_floating_ips().all()
+    pub fn list_routers(&self) -> Result<Vec<Router>> {
+        self.find_routers().all()
     }
 
-    /// List all images.
+    /// List all servers.
     ///
     /// This call can yield a lot of results, use the
-    /// [find_images](#method.find_images) call to limit the number of
-    /// images to receive.
+    /// [find_servers](#method.find_servers) call to limit the number of
+    /// servers to receive.
     ///
     /// # Example
     ///
@@ -572,14 +1621,14 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server_list = os.list_images().expect("Unable to fetch images");
+    /// let server_list = os.list_servers().expect("Unable to fetch servers");
     /// ```
-    #[cfg(feature = "image")]
-    pub fn list_images(&self) -> Result<Vec<Image>> {
-        self.find_images().all()
+    #[cfg(feature = "compute")]
+    pub fn list_servers(&self) -> Result<Vec<ServerSummary>> {
+        self.find_servers().all()
     }
 
-    /// List all key pairs.
+    /// List all server groups of the current project.
     ///
     /// # Example
     ///
@@ -587,18 +1636,39 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let result = os.list_keypairs().expect("Unable to fetch key pairs");
+    /// let groups = os.list_server_groups().expect("Unable to fetch server groups");
     /// ```
     #[cfg(feature = "compute")]
-    pub fn list_keypairs(&self) -> Result<Vec<KeyPair>> {
-        self.find_keypairs().all()
+    pub fn list_server_groups(&self) -> Result<Vec<ServerGroup>> {
+        self.find_server_groups().all()
     }
 
-    /// List all networks.
+    /// List all services in the catalog together with their endpoints.
+    ///
+    /// Listing services is usually restricted to administrators.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let has_dns = os
+    ///     .list_services()
+    ///     .expect("Unable to fetch services")
+    ///     .iter()
+    ///     .any(|service| service.service_type == "dns" && service.is_available());
+    /// ```
+    #[cfg(feature = "identity")]
+    pub fn list_services(&self) -> Result<Vec<ServiceEntry>> {
+        identity::list_services(&self.session)
+    }
+
+    /// List all IPsec site connections.
     ///
     /// This call can yield a lot of results, use the
-    /// [find_networks](#method.find_networks) call to limit the number of
-    /// networks to receive.
+    /// [find_site_connections](#method.find_site_connections) call to limit the number of
+    /// IPsec site connections to receive.
     ///
     /// # Example
     ///
@@ -606,18 +1676,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server_list = os.list_networks().expect("Unable to fetch networks");
+    /// let site_connections = os.list_site_connections().expect("Unable to fetch IPsec site connections");
     /// ```
     #[cfg(feature = "network")]
-    pub fn list_networks(&self) -> Result<Vec<Network>> {
-        self.find_networks().all()
+    pub fn list_site_connections(&self) -> Result<Vec<SiteConnection>> {
+        self.find_site_connections().all()
     }
 
-    /// List all ports.
+    /// List all subnets.
     ///
     /// This call can yield a lot of results, use the
-    /// [find_ports](#method.find_ports) call to limit the number of
-    /// ports to receive.
+    /// [find_subnets](#method.find_subnets) call to limit the number of
+    /// subnets to receive.
     ///
     /// # Example
     ///
@@ -625,18 +1695,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server_list = os.list_ports().expect("Unable to fetch ports");
+    /// let server_list = os.list_subnets().expect("Unable to fetch subnets");
     /// ```
     #[cfg(feature = "network")]
-    pub fn list_ports(&self) -> Result<Vec<Port>> {
-        self.find_ports().all()
+    pub fn list_subnets(&self) -> Result<Vec<Subnet>> {
+        self.find_subnets().all()
     }
 
-    /// List all routers.
+    /// List all subnet pools.
     ///
     /// This call can yield a lot of results, use the
-    /// [find_routers](#method.find_routers) call to limit the number of
-    /// routers to receive.
+    /// [find_subnet_pools](#method.find_subnet_pools) call to limit the number of
+    /// subnet pools to receive.
     ///
     /// # Example
     ///
@@ -644,18 +1714,18 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let router_list = os.list_routers().expect("Unable to fetch routers");
+    /// let subnet_pools = os.list_subnet_pools().expect("Unable to fetch subnet pools");
     /// ```
     #[cfg(feature = "network")]
-    pub fn list_routers(&self) -> Result<Vec<Router>> {
-        self.find_routers().all()
+    pub fn list_subnet_pools(&self) -> Result<Vec<SubnetPool>> {
+        self.find_subnet_pools().all()
     }
 
-    /// List all servers.
+    /// List all VPN services.
     ///
     /// This call can yield a lot of results, use the
-    /// [find_servers](#method.find_servers) call to limit the number of
-    /// servers to receive.
+    /// [find_vpn_services](#method.find_vpn_services) call to limit the number of
+    /// VPN services to receive.
     ///
     /// # Example
     ///
@@ -663,18 +1733,17 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server_list = os.list_servers().expect("Unable to fetch servers");
+    /// let vpn_services = os.list_vpn_services().expect("Unable to fetch VPN services");
     /// ```
-    #[cfg(feature = "compute")]
-    pub fn list_servers(&self) -> Result<Vec<ServerSummary>> {
-        self.find_servers().all()
+    #[cfg(feature = "network")]
+    pub fn list_vpn_services(&self) -> Result<Vec<VpnService>> {
+        self.find_vpn_services().all()
     }
 
-    /// List all subnets.
+    /// Prepare a new address scope for creation.
     ///
-    /// This call can yield a lot of results, use the
-    /// [find_subnets](#method.find_subnets) call to limit the number of
-    /// subnets to receive.
+    /// This call returns a `NewAddressScope` object, which is a builder to populate
+    /// address scope fields.
     ///
     /// # Example
     ///
@@ -682,11 +1751,41 @@ impl Cloud {
     /// use openstack;
     ///
     /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
-    /// let server_list = os.list_subnets().expect("Unable to fetch subnets");
+    /// let new_address_scope = os
+    ///     .new_address_scope("private-scope", openstack::network::IpVersion::V4)
+    ///     .with_shared(true)
+    ///     .create().expect("Unable to create address scope");
     /// ```
     #[cfg(feature = "network")]
-    pub fn list_subnets(&self) -> Result<Vec<Subnet>> {
-        self.find_subnets().all()
+    pub fn new_address_scope<S>(&self, name: S, ip_version: IpVersion) -> NewAddressScope
+    where
+        S: Into<String>,
+    {
+        NewAddressScope::new(self.session.clone(), name.into(), ip_version)
+    }
+
+    /// Prepare a new BGP peer for creation.
+    ///
+    /// This call returns a `NewBgpPeer` object, which is a builder to populate
+    /// BGP peer fields.
+    #[cfg(feature = "network")]
+    pub fn new_bgp_peer<S>(&self, name: S, peer_ip: std::net::IpAddr, remote_as: u32) -> NewBgpPeer
+    where
+        S: Into<String>,
+    {
+        NewBgpPeer::new(self.session.clone(), name.into(), peer_ip, remote_as)
+    }
+
+    /// Prepare a new BGP speaker for creation.
+    ///
+    /// This call returns a `NewBgpSpeaker` object, which is a builder to populate
+    /// BGP speaker fields.
+    #[cfg(feature = "network")]
+    pub fn new_bgp_speaker<S>(&self, name: S, local_as: u32) -> NewBgpSpeaker
+    where
+        S: Into<String>,
+    {
+        NewBgpSpeaker::new(self.session.clone(), name.into(), local_as)
     }
 
     /// Prepare a new object for creation.
@@ -703,6 +1802,32 @@ impl Cloud {
         NewObject::new(self.session.clone(), container.into(), object.into(), body)
     }
 
+    /// Prepare a new flavor for creation.
+    ///
+    /// This call returns a `NewFlavor` object, which is a builder to populate
+    /// flavor fields. Creating flavors is an admin-only operation.
+    ///
+    /// The RAM size is in MiB, the root disk size is in GiB.
+    #[cfg(feature = "compute")]
+    pub fn new_flavor<S>(
+        &self,
+        name: S,
+        ram_size: u64,
+        vcpu_count: u32,
+        root_size: u64,
+    ) -> NewFlavor
+    where
+        S: Into<String>,
+    {
+        NewFlavor::new(
+            self.session.clone(),
+            name.into(),
+            ram_size,
+            vcpu_count,
+            root_size,
+        )
+    }
+
     /// Prepare a new floating IP for creation.
     ///
     /// This call returns a `NewFloatingIp` object, which is a builder
@@ -715,6 +1840,33 @@ impl Cloud {
         NewFloatingIp::new(self.session.clone(), floating_network.into())
     }
 
+    /// Prepare a new flow classifier for creation.
+    ///
+    /// This call returns a `NewFlowClassifier` object, which is a builder to populate
+    /// flow classifier fields.
+    #[cfg(feature = "network")]
+    pub fn new_flow_classifier(&self) -> NewFlowClassifier {
+        NewFlowClassifier::new(self.session.clone())
+    }
+
+    /// Prepare a new IKE policy for creation.
+    ///
+    /// This call returns a `NewIkePolicy` object, which is a builder to populate
+    /// IKE policy fields.
+    #[cfg(feature = "network")]
+    pub fn new_ike_policy(&self) -> NewIkePolicy {
+        NewIkePolicy::new(self.session.clone())
+    }
+
+    /// Prepare a new IPsec policy for creation.
+    ///
+    /// This call returns a `NewIpsecPolicy` object, which is a builder to populate
+    /// IPsec policy fields.
+    #[cfg(feature = "network")]
+    pub fn new_ipsec_policy(&self) -> NewIpsecPolicy {
+        NewIpsecPolicy::new(self.session.clone())
+    }
+
     /// Prepare a new key pair for creation.
     ///
     /// This call returns a `NewKeyPair` object, which is a builder to populate
@@ -748,6 +1900,41 @@ impl Cloud {
         NewPort::new(self.session.clone(), network.into())
     }
 
+    /// Prepare a new port chain for creation.
+    ///
+    /// This call returns a `NewPortChain` object, which is a builder to populate
+    /// port chain fields.
+    #[cfg(feature = "network")]
+    pub fn new_port_chain(&self) -> NewPortChain {
+        NewPortChain::new(self.session.clone())
+    }
+
+    /// Prepare a new port pair group for creation.
+    ///
+    /// This call returns a `NewPortPairGroup` object, which is a builder to populate
+    /// port pair group fields.
+    #[cfg(feature = "network")]
+    pub fn new_port_pair_group(&self) -> NewPortPairGroup {
+        NewPortPairGroup::new(self.session.clone())
+    }
+
+    /// Prepare a new port pair for creation.
+    ///
+    /// This call returns a `NewPortPair` object, which is a builder to populate
+    /// port pair fields.
+    #[cfg(feature = "network")]
+    pub fn new_port_pair<I, E>(&self, ingress_port: I, egress_port: E) -> NewPortPair
+    where
+        I: Into<PortRef>,
+        E: Into<PortRef>,
+    {
+        NewPortPair::new(
+            self.session.clone(),
+            ingress_port.into(),
+            egress_port.into(),
+        )
+    }
+
     /// Prepare a new router for creation.
     ///
     /// This call returns a `NewRouter` object, which is a builder to populate
@@ -770,6 +1957,49 @@ impl Cloud {
         NewServer::new(self.session.clone(), name.into(), flavor.into())
     }
 
+    /// Prepare a new IPsec site connection for creation.
+    ///
+    /// This call returns a `NewSiteConnection` object, which is a builder to
+    /// populate site connection fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openstack;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let connection = os
+    ///     .new_site_connection("vpn-service", "ike-policy", "ipsec-policy", "203.0.113.1", "secret")
+    ///     .with_peer_cidrs(vec!["10.1.0.0/24".to_string()])
+    ///     .create()
+    ///     .expect("Unable to create a site connection");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn new_site_connection<V, K, I, A, P>(
+        &self,
+        vpn_service: V,
+        ike_policy: K,
+        ipsec_policy: I,
+        peer_address: A,
+        psk: P,
+    ) -> NewSiteConnection
+    where
+        V: Into<VpnServiceRef>,
+        K: Into<IkePolicyRef>,
+        I: Into<IpsecPolicyRef>,
+        A: Into<String>,
+        P: Into<String>,
+    {
+        NewSiteConnection::new(
+            self.session.clone(),
+            vpn_service.into(),
+            ike_policy.into(),
+            ipsec_policy.into(),
+            peer_address.into(),
+            psk.into(),
+        )
+    }
+
     /// Prepare a new subnet for creation.
     ///
     /// This call returns a `NewSubnet` object, which is a builder to populate
@@ -796,6 +2026,45 @@ impl Cloud {
     {
         NewSubnet::new(self.session.clone(), network.into(), cidr)
     }
+
+    /// Prepare a new subnet pool for creation.
+    ///
+    /// This call returns a `NewSubnetPool` object, which is a builder to populate
+    /// subnet pool fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// extern crate ipnet;
+    /// extern crate openstack;
+    /// use std::net;
+    ///
+    /// let os = openstack::Cloud::from_env().expect("Unable to authenticate");
+    /// let prefix = ipnet::Ipv4Net::new(net::Ipv4Addr::new(10, 0, 0, 0), 16)
+    ///     .unwrap().into();
+    /// let new_subnet_pool = os.new_subnet_pool("private-pool", vec![prefix])
+    ///     .with_default_prefixlen(24)
+    ///     .create().expect("Unable to create subnet pool");
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn new_subnet_pool<S>(&self, name: S, prefixes: Vec<ipnet::IpNet>) -> NewSubnetPool
+    where
+        S: Into<String>,
+    {
+        NewSubnetPool::new(self.session.clone(), name.into(), prefixes)
+    }
+
+    /// Prepare a new VPN service for creation.
+    ///
+    /// This call returns a `NewVpnService` object, which is a builder to populate
+    /// VPN service fields.
+    #[cfg(feature = "network")]
+    pub fn new_vpn_service<R>(&self, router: R) -> NewVpnService
+    where
+        R: Into<RouterRef>,
+    {
+        NewVpnService::new(self.session.clone(), router.into())
+    }
 }
 
 impl From<Session> for Cloud {
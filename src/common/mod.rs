@@ -17,14 +17,40 @@
 pub(crate) mod protocol;
 mod resourceiterator;
 mod types;
+#[cfg(any(
+    feature = "compute",
+    feature = "identity",
+    feature = "image",
+    feature = "network"
+))]
+mod versions;
 mod waiter;
 
 pub use osauth::ApiVersion;
 
-pub use self::resourceiterator::{ResourceIterator, ResourceQuery};
+pub use self::resourceiterator::{
+    FilteredResourceIterator, FlatMapResourceIterator, MappedResourceIterator, PageInfo,
+    PeekableResourceIterator, ResourceIterator, ResourceQuery, TakeWhileResourceIterator,
+};
 pub(crate) use self::types::IntoVerified;
 pub use self::types::{
-    ContainerRef, FlavorRef, ImageRef, KeyPairRef, NetworkRef, ObjectRef, PortRef, ProjectRef,
-    Refresh, RouterRef, SecurityGroupRef, SnapshotRef, SubnetRef, UserRef, VolumeRef,
+    AddressScopeRef, BgpPeerRef, BgpSpeakerRef, ContainerRef, FlavorRef, FlowClassifierRef,
+    IkePolicyRef, ImageRef, IpsecPolicyRef, KeyPairRef, NetworkRef, ObjectRef, PortChainRef,
+    PortPairGroupRef, PortPairRef, PortRef, ProjectRef, Refresh, RouterRef, SecurityGroupRef,
+    SnapshotRef, SubnetPoolRef, SubnetRef, Taggable, UserRef, VolumeRef, VpnServiceRef,
 };
+#[cfg(any(
+    feature = "compute",
+    feature = "identity",
+    feature = "image",
+    feature = "network"
+))]
+pub(crate) use self::versions::fetch_versions;
+#[cfg(any(
+    feature = "compute",
+    feature = "identity",
+    feature = "image",
+    feature = "network"
+))]
+pub use self::versions::{ServiceVersion, VersionStatus};
 pub use self::waiter::DeletionWaiter;
@@ -19,6 +19,7 @@
 
 use std::collections::HashMap;
 
+use osproto::common::Link;
 use reqwest::header::{HeaderMap, HeaderName};
 use reqwest::Url;
 use serde::de::Error as DeserError;
@@ -53,6 +54,19 @@ where
     Ok(value.into_iter().map(|kv| (kv.key, kv.value)).collect())
 }
 
+/// Extract the marker from the `next` pagination link (if any).
+pub fn next_marker(links: &[Link]) -> Option<String> {
+    links
+        .iter()
+        .find(|link| link.rel == "next")
+        .and_then(|link| {
+            link.href
+                .query_pairs()
+                .find(|(key, _)| key == "marker")
+                .map(|(_, value)| value.into_owned())
+        })
+}
+
 /// Get a header as a string.
 #[inline]
 pub fn get_header<'m>(headers: &'m HeaderMap, key: &HeaderName) -> Result<Option<&'m str>, Error> {
@@ -14,6 +14,7 @@
 
 //! Generic API bits for implementing new services.
 
+use std::fmt;
 use std::vec;
 
 use fallible_iterator::FallibleIterator;
@@ -39,6 +40,28 @@ pub trait ResourceQuery {
     /// Get a chunk of resources.
     fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>>;
 
+    /// Get a chunk of resources and a marker for the next chunk.
+    ///
+    /// Services that return pagination links (like the `next` link of the
+    /// Network API) should override this call to take the next marker from
+    /// the link. `None` as the next marker means that there are no more
+    /// results. The default implementation uses `fetch_chunk` and extracts
+    /// the marker from the last resource in the chunk.
+    ///
+    /// Filters that the service does not support can be applied to the chunk
+    /// on the client side. If nothing matches, return an empty chunk with the
+    /// marker of the unfiltered chunk, and the iterator will fetch the next
+    /// one.
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let chunk = self.fetch_chunk(limit, marker)?;
+        let next_marker = chunk.last().map(|item| self.extract_marker(item));
+        Ok((chunk, next_marker))
+    }
+
     /// Validate the query before the first execution.
     ///
     /// This call may modify internal representation of the query, so changing
@@ -58,6 +81,65 @@ pub struct ResourceIterator<Q: ResourceQuery> {
     validated: bool,
 }
 
+/// Pagination state of a `ResourceIterator`.
+///
+/// Created by `ResourceIterator::page_info`. Neither Compute nor Network API
+/// report the total number of resources, so only the position is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageInfo {
+    /// Marker to fetch the next page with.
+    ///
+    /// `None` means that there are no more pages. Services without pagination
+    /// links (everything except the Network API) only report it after an
+    /// empty page, so the marker may still be set on the last page.
+    pub next_marker: Option<String>,
+    /// Number of items of the current page that were not yielded yet.
+    pub remaining: usize,
+}
+
+/// A `ResourceIterator` that only yields items matching a predicate.
+///
+/// Created by `ResourceIterator::filter_ok`.
+pub struct FilteredResourceIterator<Q: ResourceQuery, P> {
+    inner: ResourceIterator<Q>,
+    predicate: P,
+}
+
+/// A `ResourceIterator` that turns every item into zero or more items.
+///
+/// Created by `ResourceIterator::flat_map`.
+pub struct FlatMapResourceIterator<Q: ResourceQuery, F, I: IntoIterator> {
+    inner: ResourceIterator<Q>,
+    func: F,
+    current: Option<I::IntoIter>,
+}
+
+/// A `ResourceIterator` that applies a function to every item.
+///
+/// Created by `ResourceIterator::map_ok`.
+pub struct MappedResourceIterator<Q: ResourceQuery, F> {
+    inner: ResourceIterator<Q>,
+    func: F,
+}
+
+/// A `ResourceIterator` that can peek at the next item.
+///
+/// Created by `ResourceIterator::peekable`.
+#[derive(Debug, Clone)]
+pub struct PeekableResourceIterator<Q: ResourceQuery> {
+    inner: ResourceIterator<Q>,
+    peeked: Option<Option<Q::Item>>,
+}
+
+/// A `ResourceIterator` that stops at the first item not matching a predicate.
+///
+/// Created by `ResourceIterator::take_while_ok`.
+pub struct TakeWhileResourceIterator<Q: ResourceQuery, P> {
+    inner: ResourceIterator<Q>,
+    predicate: P,
+    done: bool,
+}
+
 impl<Q> ResourceIterator<Q>
 where
     Q: ResourceQuery,
@@ -95,6 +177,100 @@ where
             )),
         }
     }
+
+    /// Create an iterator that only yields items for which the predicate returns `true`.
+    ///
+    /// Errors are passed through unmodified.
+    pub fn filter_ok<P>(self, predicate: P) -> FilteredResourceIterator<Q, P>
+    where
+        P: Fn(&Q::Item) -> bool,
+    {
+        FilteredResourceIterator {
+            inner: self,
+            predicate,
+        }
+    }
+
+    /// Create an iterator that turns every item into zero or more items.
+    ///
+    /// The function is fallible, its errors are returned the same way as
+    /// errors from fetching the resources.
+    pub fn flat_map<B, F, I>(self, func: F) -> FlatMapResourceIterator<Q, F, I>
+    where
+        F: FnMut(Q::Item) -> Result<I>,
+        I: IntoIterator<Item = B>,
+    {
+        FlatMapResourceIterator {
+            inner: self,
+            func,
+            current: None,
+        }
+    }
+
+    /// Create an iterator that applies a function to every item.
+    ///
+    /// Errors are passed through unmodified.
+    pub fn map_ok<B, F>(self, func: F) -> MappedResourceIterator<Q, F>
+    where
+        F: Fn(Q::Item) -> B,
+    {
+        MappedResourceIterator { inner: self, func }
+    }
+
+    /// Pagination state after the latest fetched page.
+    ///
+    /// Returns `None` before the first page is fetched.
+    pub fn page_info(&self) -> Option<PageInfo> {
+        self.cache.as_ref().map(|cache| PageInfo {
+            next_marker: if self.can_paginate == Some(true) {
+                self.marker.clone()
+            } else {
+                None
+            },
+            remaining: cache.len(),
+        })
+    }
+
+    /// Create an iterator that yields items while the predicate returns `true`.
+    ///
+    /// No more chunks are fetched after the predicate returns `false`, which
+    /// makes it a good fit for sorted queries. Errors are returned immediately.
+    pub fn take_while_ok<P>(self, predicate: P) -> TakeWhileResourceIterator<Q, P>
+    where
+        P: Fn(&Q::Item) -> bool,
+    {
+        TakeWhileResourceIterator {
+            inner: self,
+            predicate,
+            done: false,
+        }
+    }
+
+    /// Create an iterator that allows peeking at the next item.
+    ///
+    /// Peeking may fetch the next chunk of resources from the server.
+    pub fn peekable(self) -> PeekableResourceIterator<Q> {
+        PeekableResourceIterator {
+            inner: self,
+            peeked: None,
+        }
+    }
+}
+
+impl<Q> PeekableResourceIterator<Q>
+where
+    Q: ResourceQuery,
+{
+    /// Get a reference to the next item without consuming it.
+    ///
+    /// Returns `None` if the iterator is exhausted.
+    pub fn peek(&mut self) -> Result<Option<&Q::Item>> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.inner.next()?);
+        }
+
+        Ok(self.peeked.as_ref().and_then(Option::as_ref))
+    }
 }
 
 impl<Q> FallibleIterator for ResourceIterator<Q>
@@ -118,36 +294,195 @@ where
         let maybe_next = self.cache.as_mut().and_then(|cache| cache.next());
         Ok(if maybe_next.is_some() {
             maybe_next
-        } else if self.cache.is_some() && self.can_paginate == Some(false) {
+        } else if self.cache.is_some()
+            && (self.can_paginate == Some(false) || self.marker.is_none())
+        {
             // We have exhausted the results and pagination is not possible
+            // or there are no more pages.
             None
         } else {
-            let (marker, limit) = if self.can_paginate == Some(true) {
-                // can_paginate=true implies no limit was provided
-                (self.marker.clone(), Some(Q::DEFAULT_LIMIT))
-            } else {
-                (None, None)
-            };
+            loop {
+                let (marker, limit) = if self.can_paginate == Some(true) {
+                    // can_paginate=true implies no limit was provided
+                    (self.marker.take(), Some(Q::DEFAULT_LIMIT))
+                } else {
+                    (None, None)
+                };
+
+                let (chunk, next_marker) = self.query.fetch_chunk_with_links(limit, marker)?;
+                let mut iter = chunk.into_iter();
+                let maybe_next = iter.next();
+                self.cache = Some(iter);
+                self.marker = next_marker;
+
+                // A page filtered on the client side may be empty even though
+                // more pages follow.
+                if maybe_next.is_some() || self.can_paginate != Some(true) || self.marker.is_none()
+                {
+                    break maybe_next;
+                }
+            }
+        })
+    }
+}
 
-            let mut iter = self.query.fetch_chunk(limit, marker)?.into_iter();
-            let maybe_next = iter.next();
-            self.cache = Some(iter);
+impl<Q> FallibleIterator for PeekableResourceIterator<Q>
+where
+    Q: ResourceQuery,
+{
+    type Item = Q::Item;
 
-            maybe_next
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        match self.peeked.take() {
+            Some(peeked) => Ok(peeked),
+            None => self.inner.next(),
+        }
+    }
+}
+
+impl<Q, P> fmt::Debug for FilteredResourceIterator<Q, P>
+where
+    Q: ResourceQuery + fmt::Debug,
+    Q::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilteredResourceIterator")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<Q, P> FallibleIterator for FilteredResourceIterator<Q, P>
+where
+    Q: ResourceQuery,
+    P: Fn(&Q::Item) -> bool,
+{
+    type Item = Q::Item;
+
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        while let Some(item) = self.inner.next()? {
+            if (self.predicate)(&item) {
+                return Ok(Some(item));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<Q, F, I> fmt::Debug for FlatMapResourceIterator<Q, F, I>
+where
+    Q: ResourceQuery + fmt::Debug,
+    Q::Item: fmt::Debug,
+    I: IntoIterator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlatMapResourceIterator")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<Q, B, F, I> FallibleIterator for FlatMapResourceIterator<Q, F, I>
+where
+    Q: ResourceQuery,
+    F: FnMut(Q::Item) -> Result<I>,
+    I: IntoIterator<Item = B>,
+{
+    type Item = B;
+
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        loop {
+            if let Some(item) = self.current.as_mut().and_then(|current| current.next()) {
+                return Ok(Some(item));
+            }
+
+            match self.inner.next()? {
+                Some(item) => self.current = Some((self.func)(item)?.into_iter()),
+                None => return Ok(None),
+            }
         }
-        .map(|next| {
-            self.marker = Some(self.query.extract_marker(&next));
-            next
-        }))
+    }
+}
+
+impl<Q, F> fmt::Debug for MappedResourceIterator<Q, F>
+where
+    Q: ResourceQuery + fmt::Debug,
+    Q::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MappedResourceIterator")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<Q, B, F> FallibleIterator for MappedResourceIterator<Q, F>
+where
+    Q: ResourceQuery,
+    F: Fn(Q::Item) -> B,
+{
+    type Item = B;
+
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        Ok(self.inner.next()?.map(&self.func))
+    }
+}
+
+impl<Q, P> fmt::Debug for TakeWhileResourceIterator<Q, P>
+where
+    Q: ResourceQuery + fmt::Debug,
+    Q::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TakeWhileResourceIterator")
+            .field("inner", &self.inner)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<Q, P> FallibleIterator for TakeWhileResourceIterator<Q, P>
+where
+    Q: ResourceQuery,
+    P: Fn(&Q::Item) -> bool,
+{
+    type Item = Q::Item;
+
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        Ok(match self.inner.next()? {
+            Some(item) if (self.predicate)(&item) => Some(item),
+            _ => {
+                self.done = true;
+                None
+            }
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
     use fallible_iterator::FallibleIterator;
 
-    use super::super::super::Result;
-    use super::{ResourceIterator, ResourceQuery};
+    use super::super::super::{Error, ErrorKind, Result};
+    use super::{PageInfo, ResourceIterator, ResourceQuery};
 
     #[derive(Debug, PartialEq, Eq)]
     struct Test(u8);
@@ -183,6 +518,34 @@ mod test {
         }
     }
 
+    // Counts fetches and fails on any request for the second page.
+    #[derive(Debug)]
+    struct OnePageQuery(Rc<Cell<usize>>);
+
+    impl ResourceQuery for OnePageQuery {
+        type Item = Test;
+
+        const DEFAULT_LIMIT: usize = 2;
+
+        fn can_paginate(&self) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn extract_marker(&self, resource: &Test) -> String {
+            resource.0.to_string()
+        }
+
+        fn fetch_chunk(
+            &self,
+            _limit: Option<usize>,
+            marker: Option<String>,
+        ) -> Result<Vec<Self::Item>> {
+            self.0.set(self.0.get() + 1);
+            assert!(marker.is_none(), "unexpected marker {:?}", marker);
+            Ok(vec![Test(0), Test(1)])
+        }
+    }
+
     #[derive(Debug)]
     struct NoPagination;
 
@@ -210,6 +573,83 @@ mod test {
         }
     }
 
+    #[derive(Debug)]
+    struct LinkQuery;
+
+    impl ResourceQuery for LinkQuery {
+        type Item = Test;
+
+        const DEFAULT_LIMIT: usize = 2;
+
+        fn can_paginate(&self) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn extract_marker(&self, _resource: &Test) -> String {
+            panic!("markers must come from links")
+        }
+
+        fn fetch_chunk(
+            &self,
+            _limit: Option<usize>,
+            _marker: Option<String>,
+        ) -> Result<Vec<Self::Item>> {
+            panic!("fetch_chunk_with_links must be used")
+        }
+
+        fn fetch_chunk_with_links(
+            &self,
+            limit: Option<usize>,
+            marker: Option<String>,
+        ) -> Result<(Vec<Self::Item>, Option<String>)> {
+            assert_eq!(limit, Some(2));
+            Ok(match marker.as_deref() {
+                None => (vec![Test(0), Test(1)], Some("next-page".into())),
+                // The last page has no next link, no request must follow.
+                Some("next-page") => (vec![Test(2)], None),
+                Some(x) => panic!("unexpected marker {:?}", x),
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    struct EmptyPageQuery;
+
+    impl ResourceQuery for EmptyPageQuery {
+        type Item = Test;
+
+        const DEFAULT_LIMIT: usize = 2;
+
+        fn can_paginate(&self) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn extract_marker(&self, _resource: &Test) -> String {
+            panic!("markers must come from links")
+        }
+
+        fn fetch_chunk(
+            &self,
+            _limit: Option<usize>,
+            _marker: Option<String>,
+        ) -> Result<Vec<Self::Item>> {
+            panic!("fetch_chunk_with_links must be used")
+        }
+
+        fn fetch_chunk_with_links(
+            &self,
+            _limit: Option<usize>,
+            marker: Option<String>,
+        ) -> Result<(Vec<Self::Item>, Option<String>)> {
+            // Imitates a client-side filter with no matches on the first page.
+            Ok(match marker.as_deref() {
+                None => (Vec::new(), Some("next-page".into())),
+                Some("next-page") => (vec![Test(2)], None),
+                Some(x) => panic!("unexpected marker {:?}", x),
+            })
+        }
+    }
+
     #[test]
     fn test_resource_iterator() {
         let it: ResourceIterator<TestQuery> = ResourceIterator::new(TestQuery);
@@ -227,4 +667,124 @@ mod test {
             vec![Test(0), Test(1), Test(2)]
         );
     }
+
+    #[test]
+    fn test_resource_iterator_with_links() {
+        let it: ResourceIterator<LinkQuery> = ResourceIterator::new(LinkQuery);
+        assert_eq!(
+            it.collect::<Vec<Test>>().unwrap(),
+            vec![Test(0), Test(1), Test(2)]
+        );
+    }
+
+    #[test]
+    fn test_resource_iterator_empty_page() {
+        let it: ResourceIterator<EmptyPageQuery> = ResourceIterator::new(EmptyPageQuery);
+        assert_eq!(it.collect::<Vec<Test>>().unwrap(), vec![Test(2)]);
+    }
+
+    #[test]
+    fn test_resource_iterator_page_info() {
+        let mut it: ResourceIterator<LinkQuery> = ResourceIterator::new(LinkQuery);
+        assert_eq!(it.page_info(), None);
+        assert_eq!(it.next().unwrap(), Some(Test(0)));
+        assert_eq!(
+            it.page_info(),
+            Some(PageInfo {
+                next_marker: Some("next-page".into()),
+                remaining: 1,
+            })
+        );
+        assert_eq!(it.next().unwrap(), Some(Test(1)));
+        assert_eq!(it.next().unwrap(), Some(Test(2)));
+        assert_eq!(
+            it.page_info(),
+            Some(PageInfo {
+                next_marker: None,
+                remaining: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_resource_iterator_page_info_without_links() {
+        let mut it: ResourceIterator<TestQuery> = ResourceIterator::new(TestQuery);
+        assert_eq!(it.nth(3).unwrap(), Some(Test(3)));
+        // Without links the end is only known after an empty page.
+        assert_eq!(
+            it.page_info(),
+            Some(PageInfo {
+                next_marker: Some("3".into()),
+                remaining: 0,
+            })
+        );
+        assert_eq!(it.next().unwrap(), None);
+        assert_eq!(
+            it.page_info(),
+            Some(PageInfo {
+                next_marker: None,
+                remaining: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_resource_iterator_peekable() {
+        let mut it = ResourceIterator::new(TestQuery).peekable();
+        assert_eq!(it.peek().unwrap(), Some(&Test(0)));
+        assert_eq!(it.peek().unwrap(), Some(&Test(0)));
+        assert_eq!(it.next().unwrap(), Some(Test(0)));
+        assert_eq!(it.peek().unwrap(), Some(&Test(1)));
+        assert_eq!(
+            it.collect::<Vec<Test>>().unwrap(),
+            vec![Test(1), Test(2), Test(3)]
+        );
+    }
+
+    #[test]
+    fn test_resource_iterator_peekable_empty() {
+        let mut it = ResourceIterator::new(TestQuery).peekable();
+        while it.next().unwrap().is_some() {}
+        assert_eq!(it.peek().unwrap(), None);
+        assert_eq!(it.next().unwrap(), None);
+    }
+
+    #[test]
+    fn test_resource_iterator_filter_ok() {
+        let it = ResourceIterator::new(TestQuery).filter_ok(|item| item.0 % 2 == 1);
+        assert_eq!(it.collect::<Vec<Test>>().unwrap(), vec![Test(1), Test(3)]);
+    }
+
+    #[test]
+    fn test_resource_iterator_flat_map() {
+        let it =
+            ResourceIterator::new(TestQuery).flat_map(|item| Ok(vec![item.0; item.0 as usize]));
+        assert_eq!(it.collect::<Vec<u8>>().unwrap(), vec![1, 2, 2, 3, 3, 3]);
+
+        let mut it = ResourceIterator::new(TestQuery).flat_map(|item| {
+            if item.0 < 2 {
+                Ok(vec![item.0])
+            } else {
+                Err(Error::new(ErrorKind::InvalidInput, "boom"))
+            }
+        });
+        assert_eq!(it.next().unwrap(), Some(0));
+        assert_eq!(it.next().unwrap(), Some(1));
+        assert!(it.next().is_err());
+    }
+
+    #[test]
+    fn test_resource_iterator_map_ok() {
+        let it = ResourceIterator::new(TestQuery).map_ok(|item| item.0 * 10);
+        assert_eq!(it.collect::<Vec<u8>>().unwrap(), vec![0, 10, 20, 30]);
+    }
+
+    #[test]
+    fn test_resource_iterator_take_while_ok() {
+        let fetches = Rc::new(Cell::new(0));
+        let it =
+            ResourceIterator::new(OnePageQuery(fetches.clone())).take_while_ok(|item| item.0 < 1);
+        assert_eq!(it.collect::<Vec<Test>>().unwrap(), vec![Test(0)]);
+        assert_eq!(fetches.get(), 1);
+    }
 }
@@ -23,6 +23,24 @@ pub trait Refresh {
     fn refresh(&mut self) -> Result<()>;
 }
 
+/// Trait representing a resource that supports tags.
+pub trait Taggable {
+    /// Fetch the current list of tags.
+    fn tags(&self) -> Result<Vec<String>>;
+
+    /// Add a tag to the resource.
+    fn add_tag(&mut self, tag: &str) -> Result<()>;
+
+    /// Replace all tags with the provided ones.
+    fn replace_tags(&mut self, tags: &[&str]) -> Result<()>;
+
+    /// Delete a tag from the resource.
+    fn delete_tag(&mut self, tag: &str) -> Result<()>;
+
+    /// Delete all tags from the resource.
+    fn delete_all_tags(&mut self) -> Result<()>;
+}
+
 /// A type that can be converted into a verified representation.
 pub trait IntoVerified {
     /// Convert this object into the same object with verification.
@@ -79,6 +97,14 @@ macro_rules! opaque_resource_type {
             }
         }
 
+        impl ::std::ops::Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.value
+            }
+        }
+
         impl ::std::fmt::Display for $name {
             fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                 self.value.fmt(f)
@@ -121,12 +147,24 @@ macro_rules! opaque_resource_type {
     )
 }
 
+opaque_resource_type!(#[doc = "An ID of an `AddressScope`"] AddressScopeRef ? "network");
+
+opaque_resource_type!(#[doc = "An ID of a `BgpPeer`"] BgpPeerRef ? "network");
+
+opaque_resource_type!(#[doc = "An ID of a `BgpSpeaker`"] BgpSpeakerRef ? "network");
+
 opaque_resource_type!(#[doc = "An ID of a `Container`"] ContainerRef ? "object-storage");
 
 opaque_resource_type!(#[doc = "An ID of a `Flavor`"] FlavorRef ? "compute");
 
+opaque_resource_type!(#[doc = "An ID of a `FlowClassifier`"] FlowClassifierRef ? "network");
+
+opaque_resource_type!(#[doc = "An ID of an `IkePolicy`"] IkePolicyRef ? "network");
+
 opaque_resource_type!(#[doc = "An ID of an `Image`"] ImageRef ? "image");
 
+opaque_resource_type!(#[doc = "An ID of an `IpsecPolicy`"] IpsecPolicyRef ? "network");
+
 opaque_resource_type!(#[doc = "An ID of a `KeyPair`"] KeyPairRef ? "compute");
 
 opaque_resource_type!(#[doc = "An ID of a `Network`"] NetworkRef ? "network");
@@ -137,6 +175,12 @@ opaque_resource_type!(#[doc = "An ID of a `Project`"] ProjectRef ? "identity");
 
 opaque_resource_type!(#[doc = "An ID of a `Port`"] PortRef ? "network");
 
+opaque_resource_type!(#[doc = "An ID of a `PortChain`"] PortChainRef ? "network");
+
+opaque_resource_type!(#[doc = "An ID of a `PortPair`"] PortPairRef ? "network");
+
+opaque_resource_type!(#[doc = "An ID of a `PortPairGroup`"] PortPairGroupRef ? "network");
+
 opaque_resource_type!(#[doc = "An ID of a `Router`"] RouterRef ? "network");
 
 opaque_resource_type!(#[doc = "An ID of a `SecurityGroup`"] SecurityGroupRef ? "network");
@@ -145,10 +189,14 @@ opaque_resource_type!(#[doc = "An ID of a `Snapshot`"] SnapshotRef ? "volume");
 
 opaque_resource_type!(#[doc = "An ID of a `Subnet`"] SubnetRef ? "network");
 
+opaque_resource_type!(#[doc = "An ID of a `SubnetPool`"] SubnetPoolRef ? "network");
+
 opaque_resource_type!(#[doc = "An ID of a `User`"] UserRef ? "identity");
 
 opaque_resource_type!(#[doc = "An ID of a `Volume`"] VolumeRef ? "volume");
 
+opaque_resource_type!(#[doc = "An ID of a `VpnService`"] VpnServiceRef ? "network");
+
 #[cfg(test)]
 mod test {
     use serde_json;
@@ -159,6 +207,8 @@ mod test {
     fn test_opaque_type_basics() {
         let id = TestId::from("foo");
         assert_eq!(id.as_ref(), "foo");
+        assert_eq!(&*id, "foo");
+        assert_eq!(id.len(), 3);
         assert_eq!(&id.to_string(), "foo");
         assert_eq!(id, TestId::from("foo"));
         assert!(id != TestId::from("bar"));
@@ -0,0 +1,268 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Discovery of API versions supported by services.
+
+use std::fmt;
+
+use osauth::services::ServiceType;
+use osproto::common::Link;
+use reqwest::{Method, Url};
+use serde::{Deserialize, Deserializer};
+
+use super::super::session::Session;
+use super::super::{ErrorKind, Result};
+
+/// Status of an API version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VersionStatus {
+    /// The recommended version.
+    Current,
+    /// A version that is still supported, but is not the recommended one.
+    Supported,
+    /// A version that is going to be removed.
+    Deprecated,
+    /// A version that is not considered stable yet.
+    Experimental,
+    /// A status not known to this crate.
+    Unknown,
+}
+
+impl VersionStatus {
+    fn from_str(value: &str) -> VersionStatus {
+        match value.to_lowercase().as_ref() {
+            // The image service used to report its current version as stable.
+            "current" | "stable" => VersionStatus::Current,
+            "supported" => VersionStatus::Supported,
+            "deprecated" => VersionStatus::Deprecated,
+            "experimental" => VersionStatus::Experimental,
+            _ => VersionStatus::Unknown,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VersionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VersionStatus, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VersionStatus::from_str(&value))
+    }
+}
+
+impl fmt::Display for VersionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            VersionStatus::Current => "CURRENT",
+            VersionStatus::Supported => "SUPPORTED",
+            VersionStatus::Deprecated => "DEPRECATED",
+            VersionStatus::Experimental => "EXPERIMENTAL",
+            VersionStatus::Unknown => "UNKNOWN",
+        })
+    }
+}
+
+/// An API version advertised by a service.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServiceVersion {
+    /// Version identifier, e.g. `v2.1`.
+    pub id: String,
+    /// Links to the version root and its documentation.
+    #[serde(default)]
+    pub links: Vec<Link>,
+    /// Minimum supported microversion (if microversions are supported).
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub min_version: Option<String>,
+    /// Status of the version.
+    pub status: VersionStatus,
+    /// Maximum supported microversion (if microversions are supported).
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ValuesRoot {
+    values: Vec<ServiceVersion>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Versions {
+    Plain(Vec<ServiceVersion>),
+    // The identity service wraps the list into another object.
+    Values(ValuesRoot),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum VersionsRoot {
+    MultipleVersions { versions: Versions },
+    OneVersion { version: ServiceVersion },
+}
+
+impl From<VersionsRoot> for Vec<ServiceVersion> {
+    fn from(value: VersionsRoot) -> Vec<ServiceVersion> {
+        match value {
+            VersionsRoot::MultipleVersions {
+                versions: Versions::Plain(versions),
+            } => versions,
+            VersionsRoot::MultipleVersions {
+                versions: Versions::Values(root),
+            } => root.values,
+            VersionsRoot::OneVersion { version } => vec![version],
+        }
+    }
+}
+
+fn empty_as_none<'de, D>(deserializer: D) -> ::std::result::Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<String> = Deserialize::deserialize(deserializer)?;
+    Ok(value.filter(|s| !s.is_empty()))
+}
+
+/// Strip the trailing version component (e.g. `v2.1`) from an endpoint.
+fn unversioned_root(mut endpoint: Url) -> Url {
+    let last = endpoint
+        .path_segments()
+        .and_then(|segments| segments.rev().find(|s| !s.is_empty()))
+        .map(String::from);
+    if let Some(last) = last {
+        let mut chars = last.chars();
+        let is_version = chars.next() == Some('v')
+            && chars.as_str().starts_with(|c: char| c.is_ascii_digit())
+            && chars.all(|c| c.is_ascii_digit() || c == '.');
+        if is_version {
+            if let Ok(mut segments) = endpoint.path_segments_mut() {
+                let _ = segments.pop_if_empty().pop().push("");
+            }
+        }
+    }
+    endpoint
+}
+
+/// Unversioned root of a service, which serves its version document.
+///
+/// Version discovery is disabled so that the endpoint override is used as is.
+#[derive(Clone, Copy, Debug)]
+struct VersionsDocument(&'static str);
+
+impl ServiceType for VersionsDocument {
+    fn catalog_type(&self) -> &'static str {
+        self.0
+    }
+
+    fn version_discovery_supported(&self) -> bool {
+        false
+    }
+}
+
+/// Fetch all versions of the service.
+///
+/// Returns `None` if the service is not present in the catalog.
+pub(crate) fn fetch_versions<Srv>(
+    session: &Session,
+    service: Srv,
+) -> Result<Option<Vec<ServiceVersion>>>
+where
+    Srv: ServiceType + Send,
+{
+    let document = VersionsDocument(service.catalog_type());
+    let endpoint = match session.get_endpoint(service, Vec::<&str>::new()) {
+        Ok(endpoint) => endpoint,
+        Err(err) if err.kind() == ErrorKind::EndpointNotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let root = unversioned_root(endpoint);
+    debug!("Fetching API versions from {}", root);
+    // The override only affects the clone, the original session keeps its cache.
+    let versions_session = Session::new(
+        session
+            .session()
+            .clone()
+            .with_endpoint_override(document, root),
+    );
+    let builder = versions_session.request(document, Method::GET, Vec::<&str>::new(), None)?;
+    let versions: VersionsRoot = versions_session.fetch_json(builder)?;
+    Ok(Some(versions.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use reqwest::Url;
+    use serde_json;
+
+    use super::{unversioned_root, ServiceVersion, VersionStatus, VersionsRoot};
+
+    fn parse(value: &str) -> Vec<ServiceVersion> {
+        serde_json::from_str::<VersionsRoot>(value).unwrap().into()
+    }
+
+    #[test]
+    fn test_parse_versions() {
+        let versions = parse(
+            r#"{"versions": [
+                {"id": "v2.0", "status": "SUPPORTED", "version": "", "min_version": "",
+                 "links": [{"href": "http://compute/v2/", "rel": "self"}]},
+                {"id": "v2.1", "status": "CURRENT", "version": "2.79", "min_version": "2.1",
+                 "links": [{"href": "http://compute/v2.1/", "rel": "self"}]}
+            ]}"#,
+        );
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].status, VersionStatus::Supported);
+        assert_eq!(versions[0].version, None);
+        assert_eq!(versions[1].id, "v2.1");
+        assert_eq!(versions[1].status, VersionStatus::Current);
+        assert_eq!(versions[1].version.as_deref(), Some("2.79"));
+        assert_eq!(versions[1].min_version.as_deref(), Some("2.1"));
+        assert_eq!(versions[1].links.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_identity_versions() {
+        let versions = parse(
+            r#"{"versions": {"values": [
+                {"id": "v3.14", "status": "stable", "links": []}
+            ]}}"#,
+        );
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].id, "v3.14");
+        assert_eq!(versions[0].status, VersionStatus::Current);
+    }
+
+    #[test]
+    fn test_parse_one_version() {
+        let versions = parse(r#"{"version": {"id": "v2.0", "status": "weird"}}"#);
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].status, VersionStatus::Unknown);
+        assert!(versions[0].links.is_empty());
+    }
+
+    #[test]
+    fn test_unversioned_root() {
+        for (endpoint, expected) in &[
+            ("http://compute/v2.1/", "http://compute/"),
+            ("http://cloud/identity/v3", "http://cloud/identity/"),
+            ("http://network:9696/v2.0/", "http://network:9696/"),
+            ("http://network:9696/", "http://network:9696/"),
+            ("http://cloud/volume/", "http://cloud/volume/"),
+        ] {
+            let url = Url::parse(endpoint).unwrap();
+            assert_eq!(unversioned_root(url).as_str(), *expected);
+        }
+    }
+}
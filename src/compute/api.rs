@@ -24,14 +24,30 @@ use serde::Serialize;
 use super::super::common::ApiVersion;
 use super::super::session::Session;
 use super::super::utils::{self, ResultExt};
-use super::super::Result;
+use super::super::{Error, ErrorKind, Result};
 use super::protocol::*;
 
 const API_VERSION_KEYPAIR_TYPE: ApiVersion = ApiVersion(2, 2);
+const API_VERSION_REMOTE_CONSOLES: ApiVersion = ApiVersion(2, 6);
+const API_VERSION_MKS_CONSOLE: ApiVersion = ApiVersion(2, 8);
+const API_VERSION_SERVER_GROUP_USER: ApiVersion = ApiVersion(2, 13);
 const API_VERSION_SERVER_DESCRIPTION: ApiVersion = ApiVersion(2, 19);
+const API_VERSION_BLOCK_MIGRATION_AUTO: ApiVersion = ApiVersion(2, 25);
 const API_VERSION_KEYPAIR_PAGINATION: ApiVersion = ApiVersion(2, 35);
 const API_VERSION_FLAVOR_DESCRIPTION: ApiVersion = ApiVersion(2, 55);
 const API_VERSION_FLAVOR_EXTRA_SPECS: ApiVersion = ApiVersion(2, 61);
+const API_VERSION_SERVER_GROUP_POLICY: ApiVersion = ApiVersion(2, 64);
+const API_VERSION_SERVER_TOPOLOGY: ApiVersion = ApiVersion(2, 78);
+
+fn server_group_api_version(session: &Session) -> Result<Option<ApiVersion>> {
+    session.pick_api_version(
+        COMPUTE,
+        vec![
+            API_VERSION_SERVER_GROUP_USER,
+            API_VERSION_SERVER_GROUP_POLICY,
+        ],
+    )
+}
 
 fn flavor_api_version(session: &Session) -> Result<Option<ApiVersion>> {
     session.pick_api_version(
@@ -43,6 +59,16 @@ fn flavor_api_version(session: &Session) -> Result<Option<ApiVersion>> {
     )
 }
 
+/// Create a flavor.
+pub fn create_flavor(session: &Session, request: FlavorCreate) -> Result<Flavor> {
+    debug!("Creating a flavor with {:?}", request);
+    let version = flavor_api_version(session)?;
+    let body = FlavorCreateRoot { flavor: request };
+    let root: FlavorRoot = session.post_json(COMPUTE, &["flavors"], body, version)?;
+    debug!("Created flavor {:?}", root.flavor);
+    Ok(root.flavor)
+}
+
 /// Create a key pair.
 pub fn create_keypair(session: &Session, request: KeyPairCreate) -> Result<KeyPair> {
     let version = if request.key_type.is_some() {
@@ -67,6 +93,38 @@ pub fn create_server(session: &Session, request: ServerCreate) -> Result<Ref> {
     Ok(root.server)
 }
 
+/// Delete an extra spec of a flavor.
+pub fn delete_extra_spec<S1, S2>(session: &Session, id: S1, key: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    debug!(
+        "Deleting extra spec {} from flavor {}",
+        key.as_ref(),
+        id.as_ref()
+    );
+    let _ = session.delete(
+        COMPUTE,
+        &["flavors", id.as_ref(), "os-extra_specs", key.as_ref()],
+        None,
+    )?;
+    debug!(
+        "Extra spec {} was deleted from flavor {}",
+        key.as_ref(),
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Delete a flavor.
+pub fn delete_flavor<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting flavor {}", id.as_ref());
+    let _ = session.delete(COMPUTE, &["flavors", id.as_ref()], None)?;
+    debug!("Flavor {} was deleted", id.as_ref());
+    Ok(())
+}
+
 /// Delete a key pair.
 pub fn delete_keypair<S: AsRef<str>>(session: &Session, name: S) -> Result<()> {
     debug!("Deleting key pair {}", name.as_ref());
@@ -75,6 +133,14 @@ pub fn delete_keypair<S: AsRef<str>>(session: &Session, name: S) -> Result<()> {
     Ok(())
 }
 
+/// Delete a server group.
+pub fn delete_server_group<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting server group {}", id.as_ref());
+    let _ = session.delete(COMPUTE, &["os-server-groups", id.as_ref()], None)?;
+    debug!("Server group {} was deleted", id.as_ref());
+    Ok(())
+}
+
 /// Delete a server.
 pub fn delete_server<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     trace!("Deleting server {}", id.as_ref());
@@ -83,6 +149,35 @@ pub fn delete_server<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     Ok(())
 }
 
+/// Run an action on a flavor while providing some arguments.
+pub fn flavor_action_with_args<S1, S2, Q>(
+    session: &Session,
+    id: S1,
+    action: S2,
+    args: Q,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+    Q: Serialize + Send + Debug,
+{
+    trace!(
+        "Running {} on flavor {} with args {:?}",
+        action.as_ref(),
+        id.as_ref(),
+        args
+    );
+    let mut body = HashMap::new();
+    let _ = body.insert(action.as_ref(), args);
+    let _ = session.post(COMPUTE, &["flavors", id.as_ref(), "action"], body, None)?;
+    debug!(
+        "Successfully ran {} on flavor {}",
+        action.as_ref(),
+        id.as_ref()
+    );
+    Ok(())
+}
+
 /// Get a flavor by its ID.
 pub fn get_extra_specs_by_flavor_id<S: AsRef<str>>(
     session: &Session,
@@ -95,6 +190,64 @@ pub fn get_extra_specs_by_flavor_id<S: AsRef<str>>(
     Ok(root.extra_specs)
 }
 
+/// Get a URL of a remote console of a server.
+///
+/// Uses the remote consoles API when available, falls back to server actions.
+pub fn get_console_url<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    console_type: ConsoleType,
+) -> Result<String> {
+    trace!(
+        "Get {} console URL for server {}",
+        console_type,
+        id.as_ref()
+    );
+    let version = if console_type == ConsoleType::Mks {
+        API_VERSION_MKS_CONSOLE
+    } else {
+        API_VERSION_REMOTE_CONSOLES
+    };
+
+    let url = if session.supports_api_version(COMPUTE, version)? {
+        let body = RemoteConsoleCreateRoot {
+            remote_console: RemoteConsoleCreate {
+                protocol: console_type.protocol(),
+                console_type,
+            },
+        };
+        let root: RemoteConsoleRoot = session.post_json(
+            COMPUTE,
+            &["servers", id.as_ref(), "remote-consoles"],
+            body,
+            Some(version),
+        )?;
+        root.remote_console.url
+    } else {
+        let action = match console_type {
+            ConsoleType::NoVnc | ConsoleType::XvpVnc => "os-getVNCConsole",
+            ConsoleType::Spice => "os-getSPICEConsole",
+            ConsoleType::Rdp => "os-getRDPConsole",
+            ConsoleType::Serial => "os-getSerialConsole",
+            ConsoleType::Mks => {
+                return Err(Error::new(
+                    ErrorKind::IncompatibleApiVersion,
+                    "WebMKS consoles require compute API version 2.8",
+                ))
+            }
+        };
+        let mut args = HashMap::new();
+        let _ = args.insert("type", console_type);
+        let mut body = HashMap::new();
+        let _ = body.insert(action, args);
+        let root: ConsoleRoot =
+            session.post_json(COMPUTE, &["servers", id.as_ref(), "action"], body, None)?;
+        root.console.url
+    };
+    trace!("Received console URL {}", url);
+    Ok(url)
+}
+
 /// Get a flavor.
 pub fn get_flavor<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Flavor> {
     let s = id_or_name.as_ref();
@@ -148,6 +301,81 @@ pub fn get_server_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Serve
     Ok(root.server)
 }
 
+/// Get a server group.
+pub fn get_server_group<S: AsRef<str>>(session: &Session, id: S) -> Result<ServerGroup> {
+    trace!("Get compute server group {}", id.as_ref());
+    let version = server_group_api_version(session)?;
+    let root: ServerGroupRoot =
+        session.get_json(COMPUTE, &["os-server-groups", id.as_ref()], version)?;
+    trace!("Received {:?}", root.server_group);
+    Ok(root.server_group)
+}
+
+/// Get an action performed on a server.
+pub fn get_server_action<S1, S2>(
+    session: &Session,
+    id: S1,
+    request_id: S2,
+) -> Result<InstanceAction>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Get action {} of compute server {}",
+        request_id.as_ref(),
+        id.as_ref()
+    );
+    let root: InstanceActionRoot = session.get_json(
+        COMPUTE,
+        &[
+            "servers",
+            id.as_ref(),
+            "os-instance-actions",
+            request_id.as_ref(),
+        ],
+        None,
+    )?;
+    trace!("Received {:?}", root.instance_action);
+    Ok(root.instance_action)
+}
+
+/// Get an interface attachment of a server.
+pub fn get_server_interface<S1, S2>(
+    session: &Session,
+    id: S1,
+    port_id: S2,
+) -> Result<InterfaceAttachment>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Get interface {} of compute server {}",
+        port_id.as_ref(),
+        id.as_ref()
+    );
+    let root: InterfaceAttachmentRoot = session.get_json(
+        COMPUTE,
+        &["servers", id.as_ref(), "os-interface", port_id.as_ref()],
+        None,
+    )?;
+    trace!("Received {:?}", root.interface_attachment);
+    Ok(root.interface_attachment)
+}
+
+/// Get the NUMA topology of a server.
+pub fn get_server_topology<S: AsRef<str>>(session: &Session, id: S) -> Result<NumaTopology> {
+    trace!("Get NUMA topology of compute server {}", id.as_ref());
+    let topology: NumaTopology = session.get_json(
+        COMPUTE,
+        &["servers", id.as_ref(), "topology"],
+        Some(API_VERSION_SERVER_TOPOLOGY),
+    )?;
+    trace!("Received {:?}", topology);
+    Ok(topology)
+}
+
 /// Get a server by its name.
 pub fn get_server_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Server> {
     trace!("Get compute server with name {}", name.as_ref());
@@ -163,6 +391,15 @@ pub fn get_server_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<S
     .and_then(|item| get_server_by_id(session, item.id))
 }
 
+/// List projects that have access to a flavor.
+pub fn list_flavor_access<S: AsRef<str>>(session: &Session, id: S) -> Result<Vec<FlavorAccess>> {
+    trace!("Listing access to flavor {}", id.as_ref());
+    let root: FlavorAccessRoot =
+        session.get_json(COMPUTE, &["flavors", id.as_ref(), "os-flavor-access"], None)?;
+    trace!("Received flavor access: {:?}", root.flavor_access);
+    Ok(root.flavor_access)
+}
+
 /// List flavors.
 pub fn list_flavors<Q: Serialize + Sync + Debug>(
     session: &Session,
@@ -207,6 +444,61 @@ pub fn list_keypairs<Q: Serialize + Sync + Debug>(
     Ok(result)
 }
 
+/// List server groups.
+pub fn list_server_groups<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<Vec<ServerGroup>> {
+    trace!("Listing compute server groups with {:?}", query);
+    let version = server_group_api_version(session)?;
+    let root: ServerGroupsRoot =
+        session.get_json_query(COMPUTE, &["os-server-groups"], query, version)?;
+    trace!("Received server groups: {:?}", root.server_groups);
+    Ok(root.server_groups)
+}
+
+/// Start live migration of a server.
+pub fn live_migrate_server<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    mut request: LiveMigrate,
+) -> Result<()> {
+    let version = if session.supports_api_version(COMPUTE, API_VERSION_BLOCK_MIGRATION_AUTO)? {
+        // disk_over_commit was removed in 2.25.
+        request.disk_over_commit = None;
+        Some(API_VERSION_BLOCK_MIGRATION_AUTO)
+    } else if request.block_migration == BlockMigrationMode::Auto {
+        return Err(Error::new(
+            ErrorKind::IncompatibleApiVersion,
+            "Automatic block migration requires compute API version 2.25",
+        ));
+    } else {
+        // disk_over_commit is mandatory before 2.25.
+        request.disk_over_commit = Some(request.disk_over_commit.unwrap_or(false));
+        None
+    };
+
+    debug!("Live migrating server {} with {:?}", id.as_ref(), request);
+    let body = LiveMigrateRoot {
+        live_migrate: request,
+    };
+    let _ = session.post(COMPUTE, &["servers", id.as_ref(), "action"], body, version)?;
+    debug!("Started live migration of server {}", id.as_ref());
+    Ok(())
+}
+
+/// List actions performed on a server.
+pub fn list_server_actions<S: AsRef<str>>(session: &Session, id: S) -> Result<Vec<InstanceAction>> {
+    trace!("Listing actions of compute server {}", id.as_ref());
+    let root: InstanceActionsRoot = session.get_json(
+        COMPUTE,
+        &["servers", id.as_ref(), "os-instance-actions"],
+        None,
+    )?;
+    trace!("Received actions: {:?}", root.instance_actions);
+    Ok(root.instance_actions)
+}
+
 /// List servers.
 pub fn list_servers<Q: Serialize + Sync + Debug>(
     session: &Session,
@@ -269,6 +561,49 @@ where
     server_action_with_args(session, id, action, serde_json::Value::Null)
 }
 
+/// Create or update extra specs of a flavor.
+pub fn set_extra_specs<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    extra_specs: HashMap<String, String>,
+) -> Result<HashMap<String, String>> {
+    debug!(
+        "Setting extra specs {:?} on flavor {}",
+        extra_specs,
+        id.as_ref()
+    );
+    let body = ExtraSpecsRoot { extra_specs };
+    let root: ExtraSpecsRoot = session.post_json(
+        COMPUTE,
+        &["flavors", id.as_ref(), "os-extra_specs"],
+        body,
+        None,
+    )?;
+    debug!("Updated extra specs of flavor {}", id.as_ref());
+    Ok(root.extra_specs)
+}
+
+/// Update a server.
+pub fn update_server<S: AsRef<str>>(session: &Session, id: S, update: ServerUpdate) -> Result<()> {
+    let version = if update.description.is_some() {
+        Some(API_VERSION_SERVER_DESCRIPTION)
+    } else {
+        None
+    };
+
+    debug!("Updating server {} with {:?}", id.as_ref(), update);
+    let body = ServerUpdateRoot { server: update };
+    let _ = session.put(COMPUTE, &["servers", id.as_ref()], body, version)?;
+    debug!("Updated server {}", id.as_ref());
+    Ok(())
+}
+
+/// Whether the server topology API is supported.
+#[inline]
+pub fn supports_server_topology(session: &Session) -> Result<bool> {
+    session.supports_api_version(COMPUTE, API_VERSION_SERVER_TOPOLOGY)
+}
+
 /// Whether key pair pagination is supported.
 #[inline]
 pub fn supports_keypair_pagination(session: &Session) -> Result<bool> {
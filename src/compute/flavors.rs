@@ -20,7 +20,9 @@ use std::rc::Rc;
 use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
 use osproto::common::IdAndName;
 
-use super::super::common::{FlavorRef, IntoVerified, Refresh, ResourceIterator, ResourceQuery};
+use super::super::common::{
+    FlavorRef, IntoVerified, ProjectRef, Refresh, ResourceIterator, ResourceQuery,
+};
 use super::super::session::Session;
 use super::super::utils::Query;
 use super::super::{Error, Result};
@@ -41,6 +43,13 @@ pub struct FlavorSummary {
     inner: IdAndName,
 }
 
+/// A request to create a flavor.
+#[derive(Clone, Debug)]
+pub struct NewFlavor {
+    session: Rc<Session>,
+    inner: protocol::FlavorCreate,
+}
+
 /// A query to flavor list.
 #[derive(Clone, Debug)]
 pub struct FlavorQuery {
@@ -76,6 +85,31 @@ impl Flavor {
         Flavor::new(session, inner)
     }
 
+    /// Give a project access to this flavor.
+    ///
+    /// Only makes sense for private flavors. This is an admin-only operation.
+    pub fn add_access<P: Into<ProjectRef>>(&self, project: P) -> Result<()> {
+        let mut args = HashMap::new();
+        let _ = args.insert("tenant", project.into());
+        api::flavor_action_with_args(&self.session, &self.inner.id, "addTenantAccess", args)
+    }
+
+    /// Delete the flavor.
+    ///
+    /// This is an admin-only operation.
+    pub fn delete(self) -> Result<()> {
+        api::delete_flavor(&self.session, &self.inner.id)
+    }
+
+    /// Delete an extra spec of the flavor.
+    ///
+    /// This is an admin-only operation.
+    pub fn delete_extra_spec<K: AsRef<str>>(&mut self, key: K) -> Result<()> {
+        api::delete_extra_spec(&self.session, &self.inner.id, &key)?;
+        let _ = self.extra_specs.remove(key.as_ref());
+        Ok(())
+    }
+
     /// Get ephemeral disk size in GiB.
     ///
     /// Returns `0` when ephemeral disk was not requested.
@@ -98,6 +132,13 @@ impl Flavor {
         self.inner.is_public
     }
 
+    /// List projects that have access to this flavor.
+    ///
+    /// Fails for public flavors. This is an admin-only operation.
+    pub fn list_access(&self) -> Result<Vec<protocol::FlavorAccess>> {
+        api::list_flavor_access(&self.session, &self.inner.id)
+    }
+
     /// Get a reference to flavor name.
     pub fn name(&self) -> &String {
         &self.inner.name
@@ -108,11 +149,35 @@ impl Flavor {
         self.inner.ram
     }
 
+    /// Revoke access of a project to this flavor.
+    ///
+    /// This is an admin-only operation.
+    pub fn remove_access<P: Into<ProjectRef>>(&self, project: P) -> Result<()> {
+        let mut args = HashMap::new();
+        let _ = args.insert("tenant", project.into());
+        api::flavor_action_with_args(&self.session, &self.inner.id, "removeTenantAccess", args)
+    }
+
     /// Get root disk size in GiB.
     pub fn root_size(&self) -> u64 {
         self.inner.disk
     }
 
+    /// Create or update an extra spec of the flavor.
+    ///
+    /// This is an admin-only operation.
+    pub fn set_extra_spec<K, V>(&mut self, key: K, value: V) -> Result<()>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let mut extra_specs = HashMap::new();
+        let _ = extra_specs.insert(key.into(), value.into());
+        let updated = api::set_extra_specs(&self.session, &self.inner.id, extra_specs)?;
+        self.extra_specs.extend(updated);
+        Ok(())
+    }
+
     /// Get swap size in MiB.
     ///
     /// Returns `0` when swap was not requested.
@@ -284,6 +349,65 @@ impl ResourceQuery for DetailedFlavorQuery {
     }
 }
 
+impl NewFlavor {
+    /// Start creating a flavor.
+    pub(crate) fn new(
+        session: Rc<Session>,
+        name: String,
+        ram_size: u64,
+        vcpu_count: u32,
+        root_size: u64,
+    ) -> NewFlavor {
+        NewFlavor {
+            session,
+            inner: protocol::FlavorCreate {
+                disk: root_size,
+                ephemeral: None,
+                id: None,
+                is_public: None,
+                name,
+                ram: ram_size,
+                rxtx_factor: None,
+                swap: None,
+                vcpus: vcpu_count,
+            },
+        }
+    }
+
+    /// Request creation of the flavor.
+    ///
+    /// This is an admin-only operation.
+    pub fn create(self) -> Result<Flavor> {
+        let inner = api::create_flavor(&self.session, self.inner)?;
+        Flavor::new(self.session, inner)
+    }
+
+    creation_inner_field! {
+        #[doc = "Set ephemeral disk size in GiB."]
+        set_ephemeral_size, with_ephemeral_size -> ephemeral: optional u64
+    }
+
+    creation_inner_field! {
+        #[doc = "Set ID of the flavor (generated by the server by default)."]
+        set_id, with_id -> id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the flavor is public (the default) or private."]
+        set_public, with_public -> is_public: optional bool
+    }
+
+    creation_inner_field! {
+        #[doc = "Set RX/TX factor of the flavor."]
+        set_rxtx_factor, with_rxtx_factor -> rxtx_factor: optional f32
+    }
+
+    creation_inner_field! {
+        #[doc = "Set swap size in MiB."]
+        set_swap_size, with_swap_size -> swap: optional u64
+    }
+}
+
 impl IntoFallibleIterator for FlavorQuery {
     type Item = FlavorSummary;
 
@@ -314,12 +438,24 @@ impl From<Flavor> for FlavorRef {
     }
 }
 
+impl<'a> From<&'a Flavor> for FlavorRef {
+    fn from(value: &'a Flavor) -> FlavorRef {
+        FlavorRef::new_verified(value.inner.id.clone())
+    }
+}
+
 impl From<FlavorSummary> for FlavorRef {
     fn from(value: FlavorSummary) -> FlavorRef {
         FlavorRef::new_verified(value.inner.id)
     }
 }
 
+impl<'a> From<&'a FlavorSummary> for FlavorRef {
+    fn from(value: &'a FlavorSummary) -> FlavorRef {
+        FlavorRef::new_verified(value.inner.id.clone())
+    }
+}
+
 #[cfg(feature = "compute")]
 impl IntoVerified for FlavorRef {
     /// Verify this reference and convert to an ID, if possible.
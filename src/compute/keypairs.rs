@@ -37,6 +37,7 @@ pub struct KeyPairQuery {
     session: Rc<Session>,
     query: Query,
     can_paginate: bool,
+    key_type: Option<protocol::KeyPairType>,
 }
 
 /// A request to create a key pair.
@@ -72,6 +73,16 @@ impl KeyPair {
         #[doc = "Key pair name."]
         name: ref String
     }
+
+    transparent_property! {
+        #[doc = "Public key."]
+        public_key: ref String
+    }
+
+    transparent_property! {
+        #[doc = "ID of the user owning the key pair (if available)."]
+        user_id: ref Option<String>
+    }
 }
 
 impl Refresh for KeyPair {
@@ -88,6 +99,7 @@ impl KeyPairQuery {
             session,
             query: Query::new(),
             can_paginate: true,
+            key_type: None,
         }
     }
 
@@ -109,6 +121,19 @@ impl KeyPairQuery {
         self
     }
 
+    /// Filter by key pair type.
+    ///
+    /// Key pairs of other types are still fetched and dropped locally.
+    pub fn set_key_type(&mut self, value: protocol::KeyPairType) {
+        self.key_type = Some(value);
+    }
+
+    /// Filter by key pair type.
+    pub fn with_key_type(mut self, value: protocol::KeyPairType) -> Self {
+        self.set_key_type(value);
+        self
+    }
+
     /// Convert this query into an iterator executing the request.
     ///
     /// Returns a `FallibleIterator`, which is an iterator with each `next`
@@ -226,14 +251,32 @@ impl ResourceQuery for KeyPairQuery {
     }
 
     fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
         let query = self.query.with_marker_and_limit(limit, marker);
-        Ok(api::list_keypairs(&self.session, &query)?
-            .into_iter()
-            .map(|item| KeyPair {
-                session: self.session.clone(),
-                inner: item,
-            })
-            .collect())
+        let mut items = api::list_keypairs(&self.session, &query)?;
+        // Use the unfiltered page for the marker to avoid fetching it again.
+        let next_marker = items.last().map(|item| item.name.clone());
+        if let Some(key_type) = self.key_type {
+            items.retain(|item| item.key_type == Some(key_type));
+        }
+
+        Ok((
+            items
+                .into_iter()
+                .map(|item| KeyPair {
+                    session: self.session.clone(),
+                    inner: item,
+                })
+                .collect(),
+            next_marker,
+        ))
     }
 }
 
@@ -255,6 +298,12 @@ impl From<KeyPair> for KeyPairRef {
     }
 }
 
+impl<'a> From<&'a KeyPair> for KeyPairRef {
+    fn from(value: &'a KeyPair) -> KeyPairRef {
+        KeyPairRef::new_verified(value.inner.name.clone())
+    }
+}
+
 #[cfg(feature = "compute")]
 impl IntoVerified for KeyPairRef {
     /// Verify this reference and convert to an ID, if possible.
@@ -19,16 +19,19 @@ mod block_device_mapping;
 mod flavors;
 mod keypairs;
 mod protocol;
+mod server_groups;
 mod servers;
 
 pub use self::block_device_mapping::{BlockDevice, BlockDeviceDestinationType, BlockDeviceSource};
-pub use self::flavors::{DetailedFlavorQuery, Flavor, FlavorQuery, FlavorSummary};
+pub use self::flavors::{DetailedFlavorQuery, Flavor, FlavorQuery, FlavorSummary, NewFlavor};
 pub use self::keypairs::{KeyPair, KeyPairQuery, NewKeyPair};
 pub use self::protocol::{
-    AddressType, KeyPairType, RebootType, ServerAddress, ServerFlavor, ServerPowerState,
-    ServerSortKey, ServerStatus,
+    AddressType, BlockMigrationMode, ConsoleType, FlavorAccess, InstanceAction,
+    InstanceActionEvent, InterfaceFixedIp, KeyPairType, NumaNode, NumaTopology, RebootType,
+    ServerAddress, ServerFlavor, ServerGroupPolicy, ServerPowerState, ServerSortKey, ServerStatus,
 };
+pub use self::server_groups::{ServerGroup, ServerGroupQuery};
 pub use self::servers::{
-    DetailedServerQuery, NewServer, Server, ServerCreationWaiter, ServerNIC, ServerQuery,
-    ServerStatusWaiter, ServerSummary,
+    DetailedServerQuery, InterfaceAttachment, LiveMigrateBuilder, NewServer, Server,
+    ServerCreationWaiter, ServerNIC, ServerQuery, ServerStatusWaiter, ServerSummary,
 };
@@ -20,7 +20,7 @@
 use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
 use osproto::common::{empty_as_default, IdAndName, Ref};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
@@ -98,6 +98,32 @@ protocol_enum! {
     }
 }
 
+/// Block migration mode for live migration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BlockMigrationMode {
+    /// Let the Compute service decide (requires API version 2.25).
+    #[default]
+    Auto,
+    /// Copy the disks of the server to the destination host.
+    Enabled,
+    /// Do not copy disks, requires shared storage.
+    Disabled,
+}
+
+impl Serialize for BlockMigrationMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            BlockMigrationMode::Auto => serializer.serialize_str("auto"),
+            BlockMigrationMode::Enabled => serializer.serialize_bool(true),
+            BlockMigrationMode::Disabled => serializer.serialize_bool(false),
+        }
+    }
+}
+
 protocol_enum! {
     #[doc = "Reboot type."]
     enum RebootType {
@@ -106,6 +132,16 @@ protocol_enum! {
     }
 }
 
+protocol_enum! {
+    #[doc = "Scheduling policy of a server group."]
+    enum ServerGroupPolicy {
+        Affinity = "affinity",
+        AntiAffinity = "anti-affinity",
+        SoftAffinity = "soft-affinity",
+        SoftAntiAffinity = "soft-anti-affinity"
+    }
+}
+
 protocol_enum! {
     #[doc = "Type of a server address."]
     enum AddressType {
@@ -114,6 +150,37 @@ protocol_enum! {
     }
 }
 
+protocol_enum! {
+    #[doc = "Type of a remote console."]
+    enum ConsoleType {
+        #[doc = "noVNC (HTML5) console."]
+        NoVnc = "novnc",
+        #[doc = "XVP VNC console."]
+        XvpVnc = "xvpvnc",
+        #[doc = "SPICE (HTML5) console."]
+        Spice = "spice-html5",
+        #[doc = "RDP (HTML5) console."]
+        Rdp = "rdp-html5",
+        #[doc = "Serial console."]
+        Serial = "serial",
+        #[doc = "WebMKS console (requires compute API 2.8)."]
+        Mks = "webmks"
+    }
+}
+
+impl ConsoleType {
+    /// Remote console protocol of this console type.
+    pub fn protocol(self) -> &'static str {
+        match self {
+            ConsoleType::NoVnc | ConsoleType::XvpVnc => "vnc",
+            ConsoleType::Spice => "spice",
+            ConsoleType::Rdp => "rdp",
+            ConsoleType::Serial => "serial",
+            ConsoleType::Mks => "mks",
+        }
+    }
+}
+
 protocol_enum! {
     #[doc = "Type of a key pair."]
     enum KeyPairType {
@@ -135,7 +202,7 @@ pub struct ServerAddress {
     pub addr_type: Option<AddressType>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ExtraSpecsRoot {
     pub extra_specs: HashMap<String, String>,
 }
@@ -159,6 +226,100 @@ pub struct ServerFlavor {
     pub vcpu_count: u32,
 }
 
+impl ServerFlavor {
+    /// NUMA topology requested by the `hw:numa_*` extra specs.
+    ///
+    /// Nodes without explicit `hw:numa_cpus.N` and `hw:numa_mem.N` get an
+    /// even share of the CPUs and memory, like the Compute service does.
+    pub(crate) fn numa_topology(&self) -> Option<NumaTopology> {
+        let extra_specs = self.extra_specs.as_ref()?;
+        let node_count: u32 = extra_specs.get("hw:numa_nodes")?.parse().ok()?;
+        if node_count == 0 {
+            return None;
+        }
+
+        let cpus_per_node = self.vcpu_count / node_count;
+        let nodes = (0..node_count)
+            .map(|idx| {
+                let vcpu_set = match extra_specs.get(&format!("hw:numa_cpus.{}", idx)) {
+                    Some(spec) => parse_cpu_spec(spec),
+                    None => (idx * cpus_per_node..(idx + 1) * cpus_per_node).collect(),
+                };
+                let memory_mb = match extra_specs.get(&format!("hw:numa_mem.{}", idx)) {
+                    Some(mem) => mem.parse().ok(),
+                    None => Some(self.ram_size / u64::from(node_count)),
+                };
+                NumaNode {
+                    cpu_pinning: HashMap::new(),
+                    host_node: None,
+                    memory_mb,
+                    siblings: Vec::new(),
+                    vcpu_set,
+                }
+            })
+            .collect();
+
+        Some(NumaTopology {
+            nodes,
+            pagesize_kb: extra_specs
+                .get("hw:mem_page_size")
+                .and_then(|size| size.parse().ok()),
+        })
+    }
+}
+
+/// Parse a CPU set specification like `0-3,^2,5`.
+fn parse_cpu_spec(spec: &str) -> Vec<u32> {
+    let mut included = Vec::new();
+    let mut excluded = Vec::new();
+    for item in spec.split(',').map(str::trim) {
+        let (target, item) = match item.strip_prefix('^') {
+            Some(rest) => (&mut excluded, rest),
+            None => (&mut included, item),
+        };
+        let mut bounds = item.splitn(2, '-').map(|x| x.trim().parse::<u32>());
+        match (bounds.next(), bounds.next()) {
+            (Some(Ok(start)), Some(Ok(end))) => target.extend(start..=end),
+            (Some(Ok(cpu)), None) => target.push(cpu),
+            _ => {}
+        }
+    }
+    included.retain(|cpu| !excluded.contains(cpu));
+    included.sort_unstable();
+    included.dedup();
+    included
+}
+
+/// A guest NUMA node of a server.
+#[derive(Clone, Debug, Deserialize)]
+pub struct NumaNode {
+    /// Mapping of guest CPUs to host CPUs (only visible to administrators).
+    #[serde(default)]
+    pub cpu_pinning: HashMap<u32, u32>,
+    /// Host NUMA node (only visible to administrators).
+    #[serde(default)]
+    pub host_node: Option<u32>,
+    /// Memory in MiB (if known).
+    #[serde(default)]
+    pub memory_mb: Option<u64>,
+    /// Sibling guest CPUs (threads of the same core).
+    #[serde(default)]
+    pub siblings: Vec<Vec<u32>>,
+    /// Guest CPUs of this node.
+    #[serde(default)]
+    pub vcpu_set: Vec<u32>,
+}
+
+/// NUMA topology of a server.
+#[derive(Clone, Debug, Deserialize)]
+pub struct NumaTopology {
+    /// Guest NUMA nodes.
+    pub nodes: Vec<NumaNode>,
+    /// Page size in KiB (if known).
+    #[serde(default)]
+    pub pagesize_kb: Option<u64>,
+}
+
 fn bool_from_config_drive_string<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: Deserializer<'de>,
@@ -173,6 +334,37 @@ where
     }
 }
 
+fn parse_compute_datetime<E: de::Error>(value: &str) -> Result<DateTime<FixedOffset>, E> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| {
+            // Older API versions omit the time zone, the time is always UTC.
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+                .map(|naive| Utc.from_utc_datetime(&naive).into())
+        })
+        .map_err(|_| {
+            de::Error::invalid_value(de::Unexpected::Str(value), &"ISO 8601 date and time")
+        })
+}
+
+fn compute_datetime<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_compute_datetime(&String::deserialize(deserializer)?)
+}
+
+fn optional_compute_datetime<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<FixedOffset>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(value) => parse_compute_datetime(&value).map(Some),
+        None => Ok(None),
+    }
+}
+
 #[allow(clippy::trivially_copy_pass_by_ref)]
 fn config_drive_string_to_bool<S>(has_config_drive: &Option<bool>, s: S) -> Result<S::Ok, S::Error>
 where
@@ -221,6 +413,143 @@ pub struct Server {
     #[serde(rename = "updated")]
     pub updated_at: DateTime<FixedOffset>,
     pub user_id: String,
+    // Fields not known to this crate.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ServerUpdate {
+    // Some(None) removes the description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ServerUpdateRoot {
+    pub server: ServerUpdate,
+}
+
+/// A fixed IP address of an interface attachment.
+#[derive(Clone, Debug, Deserialize)]
+pub struct InterfaceFixedIp {
+    /// IP (v4 or v6) address.
+    pub ip_address: IpAddr,
+    /// ID of the subnet of the address.
+    pub subnet_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InterfaceAttachment {
+    #[serde(default)]
+    pub fixed_ips: Vec<InterfaceFixedIp>,
+    pub mac_addr: String,
+    pub net_id: String,
+    pub port_id: String,
+    pub port_state: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InterfaceAttachmentRoot {
+    #[serde(rename = "interfaceAttachment")]
+    pub interface_attachment: InterfaceAttachment,
+}
+
+/// An event of an instance action.
+#[derive(Clone, Debug, Deserialize)]
+pub struct InstanceActionEvent {
+    /// Name of the event.
+    pub event: String,
+    /// When the event finished (if it has finished).
+    #[serde(deserialize_with = "optional_compute_datetime", default)]
+    pub finish_time: Option<DateTime<FixedOffset>>,
+    /// Result of the event (if it has finished), e.g. `Success` or `Error`.
+    #[serde(default)]
+    pub result: Option<String>,
+    /// When the event started.
+    #[serde(deserialize_with = "compute_datetime")]
+    pub start_time: DateTime<FixedOffset>,
+}
+
+/// An action performed on a server.
+#[derive(Clone, Debug, Deserialize)]
+pub struct InstanceAction {
+    /// Name of the action, e.g. `create` or `reboot`.
+    pub action: String,
+    /// Events of the action (only returned when fetching a single action).
+    #[serde(default)]
+    pub events: Vec<InstanceActionEvent>,
+    /// ID of the server.
+    pub instance_uuid: String,
+    /// Error message (if the action failed).
+    #[serde(default)]
+    pub message: Option<String>,
+    /// ID of the project that requested the action.
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// ID of the request that caused the action.
+    pub request_id: String,
+    /// When the action started.
+    #[serde(deserialize_with = "compute_datetime")]
+    pub start_time: DateTime<FixedOffset>,
+    /// ID of the user that requested the action.
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InstanceActionRoot {
+    #[serde(rename = "instanceAction")]
+    pub instance_action: InstanceAction,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InstanceActionsRoot {
+    #[serde(rename = "instanceActions")]
+    pub instance_actions: Vec<InstanceAction>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct LiveMigrate {
+    pub block_migration: BlockMigrationMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_over_commit: Option<bool>,
+    pub host: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LiveMigrateRoot {
+    #[serde(rename = "os-migrateLive")]
+    pub live_migrate: LiveMigrate,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RemoteConsoleCreate {
+    pub protocol: &'static str,
+    #[serde(rename = "type")]
+    pub console_type: ConsoleType,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RemoteConsoleCreateRoot {
+    pub remote_console: RemoteConsoleCreate,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoteConsole {
+    pub url: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoteConsoleRoot {
+    pub remote_console: RemoteConsole,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConsoleRoot {
+    pub console: RemoteConsole,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -243,7 +572,7 @@ pub struct ServerRoot {
 pub enum ServerNetwork {
     Network { uuid: String },
     Port { port: String },
-    FixedIp { fixed_ip: Ipv4Addr },
+    FixedIp { fixed_ip: IpAddr },
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -306,6 +635,20 @@ pub struct Flavor {
     pub vcpus: u32,
 }
 
+/// Access of a project to a private flavor.
+#[derive(Clone, Debug, Deserialize)]
+pub struct FlavorAccess {
+    /// ID of the flavor.
+    pub flavor_id: String,
+    /// ID of the project that has access to the flavor.
+    pub tenant_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FlavorAccessRoot {
+    pub flavor_access: Vec<FlavorAccess>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct FlavorsRoot {
     pub flavors: Vec<IdAndName>,
@@ -316,6 +659,35 @@ pub struct FlavorsDetailRoot {
     pub flavors: Vec<Flavor>,
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct FlavorCreate {
+    pub disk: u64,
+    #[serde(
+        rename = "OS-FLV-EXT-DATA:ephemeral",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ephemeral: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        rename = "os-flavor-access:is_public",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub is_public: Option<bool>,
+    pub name: String,
+    pub ram: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rxtx_factor: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swap: Option<u64>,
+    pub vcpus: u32,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FlavorCreateRoot {
+    pub flavor: FlavorCreate,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct FlavorRoot {
     pub flavor: Flavor,
@@ -330,6 +702,8 @@ pub struct KeyPair {
     #[serde(default, skip_serializing)]
     pub private_key: Option<String>,
     pub public_key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -366,6 +740,32 @@ pub struct KeyPairsRoot {
     pub keypairs: Vec<KeyPairRoot>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerGroup {
+    pub id: String,
+    #[serde(default)]
+    pub members: Vec<String>,
+    pub name: String,
+    #[serde(default)]
+    pub policies: Vec<ServerGroupPolicy>,
+    #[serde(default)]
+    pub policy: Option<ServerGroupPolicy>,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerGroupRoot {
+    pub server_group: ServerGroup,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerGroupsRoot {
+    pub server_groups: Vec<ServerGroup>,
+}
+
 impl Default for ServerStatus {
     fn default() -> ServerStatus {
         ServerStatus::Unknown
@@ -382,3 +782,195 @@ impl Default for ServerPowerState {
 fn default_flavor_is_public() -> bool {
     true
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use std::collections::HashMap;
+
+    use super::{
+        BlockMigrationMode, InstanceActionRoot, NumaTopology, RebootType, ServerFlavor,
+        ServerStatus, ServerUpdate,
+    };
+
+    #[test]
+    fn test_reboot_type_serialization() {
+        assert_eq!(
+            serde_json::to_string(&RebootType::Hard).unwrap(),
+            "\"HARD\""
+        );
+        assert_eq!(
+            serde_json::to_string(&RebootType::Soft).unwrap(),
+            "\"SOFT\""
+        );
+        let value: RebootType = serde_json::from_str("\"SOFT\"").unwrap();
+        assert_eq!(value, RebootType::Soft);
+    }
+
+    #[test]
+    fn test_block_migration_mode_serialization() {
+        assert_eq!(
+            serde_json::to_string(&BlockMigrationMode::Auto).unwrap(),
+            "\"auto\""
+        );
+        assert_eq!(
+            serde_json::to_string(&BlockMigrationMode::Enabled).unwrap(),
+            "true"
+        );
+        assert_eq!(
+            serde_json::to_string(&BlockMigrationMode::Disabled).unwrap(),
+            "false"
+        );
+    }
+
+    #[test]
+    fn test_server_update_serialization() {
+        let update = ServerUpdate {
+            description: Some(None),
+            name: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&update).unwrap(),
+            r#"{"description":null}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ServerUpdate::default()).unwrap(),
+            "{}"
+        );
+    }
+
+    fn flavor_with_extra_specs(extra_specs: &[(&str, &str)]) -> ServerFlavor {
+        ServerFlavor {
+            ephemeral_size: 0,
+            extra_specs: Some(
+                extra_specs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            ),
+            original_name: "flavor".into(),
+            ram_size: 4096,
+            root_size: 10,
+            swap_size: 0,
+            vcpu_count: 4,
+        }
+    }
+
+    #[test]
+    fn test_flavor_numa_topology_even() {
+        let flavor = flavor_with_extra_specs(&[("hw:numa_nodes", "2")]);
+        let topology = flavor.numa_topology().unwrap();
+        assert_eq!(topology.nodes.len(), 2);
+        assert_eq!(topology.nodes[0].vcpu_set, vec![0, 1]);
+        assert_eq!(topology.nodes[1].vcpu_set, vec![2, 3]);
+        assert_eq!(topology.nodes[1].memory_mb, Some(2048));
+        assert_eq!(topology.pagesize_kb, None);
+    }
+
+    #[test]
+    fn test_flavor_numa_topology_explicit() {
+        let flavor = flavor_with_extra_specs(&[
+            ("hw:numa_nodes", "2"),
+            ("hw:numa_cpus.0", "0-2,^1"),
+            ("hw:numa_cpus.1", "1,3"),
+            ("hw:numa_mem.0", "1024"),
+            ("hw:numa_mem.1", "3072"),
+            ("hw:mem_page_size", "2048"),
+        ]);
+        let topology = flavor.numa_topology().unwrap();
+        assert_eq!(topology.nodes[0].vcpu_set, vec![0, 2]);
+        assert_eq!(topology.nodes[1].vcpu_set, vec![1, 3]);
+        assert_eq!(topology.nodes[0].memory_mb, Some(1024));
+        assert_eq!(topology.nodes[1].memory_mb, Some(3072));
+        assert_eq!(topology.pagesize_kb, Some(2048));
+    }
+
+    #[test]
+    fn test_flavor_numa_topology_none() {
+        assert!(flavor_with_extra_specs(&[]).numa_topology().is_none());
+        let mut flavor = flavor_with_extra_specs(&[]);
+        flavor.extra_specs = None;
+        assert!(flavor.numa_topology().is_none());
+    }
+
+    #[test]
+    fn test_numa_topology_deserialization() {
+        let value: NumaTopology = serde_json::from_str(
+            r#"{"nodes": [{"cpu_pinning": {"0": 1, "1": 5}, "host_node": 0,
+                           "memory_mb": 1024, "siblings": [[0, 1]],
+                           "vcpu_set": [0, 1]}],
+                "pagesize_kb": 4}"#,
+        )
+        .unwrap();
+        assert_eq!(value.nodes[0].cpu_pinning, {
+            let mut expected = HashMap::new();
+            let _ = expected.insert(0, 1);
+            let _ = expected.insert(1, 5);
+            expected
+        });
+        assert_eq!(value.nodes[0].siblings, vec![vec![0, 1]]);
+        assert_eq!(value.pagesize_kb, Some(4));
+    }
+
+    #[test]
+    fn test_instance_action_deserialization() {
+        let value: InstanceActionRoot = serde_json::from_str(
+            r#"{"instanceAction": {
+                "action": "reboot", "instance_uuid": "1234", "message": null,
+                "project_id": "p1", "request_id": "req-1", "user_id": "u1",
+                "start_time": "2018-04-25T01:26:29.000000",
+                "events": [{"event": "compute_reboot_instance", "result": null,
+                            "start_time": "2018-04-25T01:26:29+00:00",
+                            "finish_time": null}]}}"#,
+        )
+        .unwrap();
+        let action = value.instance_action;
+        assert_eq!(action.action, "reboot");
+        assert_eq!(action.message, None);
+        assert_eq!(action.start_time.to_rfc3339(), "2018-04-25T01:26:29+00:00");
+        assert_eq!(action.events.len(), 1);
+        assert_eq!(action.events[0].result, None);
+        assert_eq!(action.events[0].finish_time, None);
+        assert_eq!(action.events[0].start_time, action.start_time);
+    }
+
+    #[test]
+    fn test_server_status_from_str() {
+        let all = [
+            ServerStatus::Active,
+            ServerStatus::Building,
+            ServerStatus::Deleted,
+            ServerStatus::Error,
+            ServerStatus::HardRebooting,
+            ServerStatus::Migrating,
+            ServerStatus::Paused,
+            ServerStatus::Rebooting,
+            ServerStatus::Resizing,
+            ServerStatus::RevertingResize,
+            ServerStatus::ShutOff,
+            ServerStatus::Suspended,
+            ServerStatus::Rescuing,
+            ServerStatus::Shelved,
+            ServerStatus::ShelvedOffloaded,
+            ServerStatus::SoftDeleted,
+            ServerStatus::Unknown,
+            ServerStatus::UpdatingPassword,
+            ServerStatus::VerifyingResize,
+        ];
+        for status in all.iter() {
+            let value = status.to_string();
+            assert_eq!(value, value.to_uppercase());
+            assert_eq!(value.parse::<ServerStatus>().unwrap(), *status);
+            assert_eq!(
+                value.to_lowercase().parse::<ServerStatus>().unwrap(),
+                *status
+            );
+        }
+        assert_eq!(
+            "Active".parse::<ServerStatus>().unwrap(),
+            ServerStatus::Active
+        );
+        assert!("NOT_A_STATUS".parse::<ServerStatus>().is_err());
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Server group management via Compute API.
+
+use std::rc::Rc;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+use osproto::common::IdAndName;
+
+use super::super::common::{Refresh, ResourceIterator, ResourceQuery};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol, Server, ServerSummary};
+
+/// Structure representing a server group.
+#[derive(Clone, Debug)]
+pub struct ServerGroup {
+    session: Rc<Session>,
+    inner: protocol::ServerGroup,
+}
+
+/// A query to server group list.
+#[derive(Clone, Debug)]
+pub struct ServerGroupQuery {
+    session: Rc<Session>,
+    query: Query,
+}
+
+impl ServerGroup {
+    /// Create a ServerGroup object.
+    pub(crate) fn new(session: Rc<Session>, inner: protocol::ServerGroup) -> ServerGroup {
+        ServerGroup { session, inner }
+    }
+
+    /// Load a ServerGroup object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<ServerGroup> {
+        let inner = api::get_server_group(&session, id)?;
+        Ok(ServerGroup::new(session, inner))
+    }
+
+    /// Whether the server is a member of this group.
+    pub fn contains(&self, server: &Server) -> bool {
+        self.inner.members.contains(server.id())
+    }
+
+    /// Delete the server group.
+    pub fn delete(self) -> Result<()> {
+        api::delete_server_group(&self.session, &self.inner.id)
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    /// IDs of the servers in this group.
+    pub fn member_ids(&self) -> &Vec<String> {
+        &self.inner.members
+    }
+
+    /// Fetch the servers in this group.
+    pub fn members(&self) -> Result<Vec<ServerSummary>> {
+        self.inner
+            .members
+            .iter()
+            .map(|id| {
+                let server = api::get_server_by_id(&self.session, id)?;
+                Ok(ServerSummary::new(
+                    self.session.clone(),
+                    IdAndName {
+                        id: server.id,
+                        name: server.name,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    transparent_property! {
+        #[doc = "Server group name."]
+        name: ref String
+    }
+
+    /// Scheduling policy of the server group.
+    pub fn policy(&self) -> Option<protocol::ServerGroupPolicy> {
+        // Compute API before 2.64 returns a list with exactly one policy.
+        self.inner
+            .policy
+            .or_else(|| self.inner.policies.first().cloned())
+    }
+
+    transparent_property! {
+        #[doc = "ID of the project owning the server group (if available)."]
+        project_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "ID of the user owning the server group (if available)."]
+        user_id: ref Option<String>
+    }
+}
+
+impl Refresh for ServerGroup {
+    /// Refresh the server group.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_server_group(&self.session, &self.inner.id)?;
+        Ok(())
+    }
+}
+
+impl ServerGroupQuery {
+    pub(crate) fn new(session: Rc<Session>) -> ServerGroupQuery {
+        ServerGroupQuery {
+            session,
+            query: Query::new(),
+        }
+    }
+
+    /// List server groups of all projects (requires administrator rights).
+    pub fn with_all_projects(mut self) -> Self {
+        self.query.push("all_projects", true);
+        self
+    }
+
+    /// Add limit to the request.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<ServerGroupQuery> {
+        debug!("Fetching server groups with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<ServerGroup>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(self) -> Result<ServerGroup> {
+        debug!("Fetching one server group with {:?}", self.query);
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for ServerGroupQuery {
+    type Item = ServerGroup;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        // The server groups API uses offsets instead of markers.
+        Ok(false)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        Ok(api::list_server_groups(&self.session, &query)?
+            .into_iter()
+            .map(|item| ServerGroup::new(self.session.clone(), item))
+            .collect())
+    }
+}
+
+impl IntoFallibleIterator for ServerGroupQuery {
+    type Item = ServerGroup;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<ServerGroupQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
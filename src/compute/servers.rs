@@ -14,9 +14,10 @@
 
 //! Server management via Compute API.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::rc::Rc;
+use std::thread;
 use std::time::Duration;
 
 use chrono::{DateTime, FixedOffset};
@@ -30,10 +31,12 @@ use super::super::common::{
 };
 #[cfg(feature = "image")]
 use super::super::image::Image;
+#[cfg(feature = "network")]
+use super::super::network::{FloatingIp, FloatingIpQuery, NewFloatingIp, Port, PortQuery};
 use super::super::session::Session;
-use super::super::utils::Query;
+use super::super::utils::{Query, ValueCache};
 use super::super::{Error, ErrorKind, Result, Sort};
-use super::{api, protocol, BlockDevice, KeyPair};
+use super::{api, protocol, BlockDevice, KeyPair, ServerGroup, ServerGroupQuery};
 
 /// A query to server list.
 #[derive(Clone, Debug)]
@@ -57,6 +60,8 @@ pub struct Server {
     session: Rc<Session>,
     inner: protocol::Server,
     flavor: protocol::ServerFlavor,
+    server_group: ValueCache<Option<ServerGroup>>,
+    dirty: HashSet<&'static str>,
 }
 
 /// Structure representing a summary of a single server.
@@ -73,6 +78,19 @@ pub struct ServerStatusWaiter<'server> {
     target: protocol::ServerStatus,
 }
 
+/// An interface (port) attached to a server.
+#[derive(Clone, Debug)]
+pub struct InterfaceAttachment {
+    inner: protocol::InterfaceAttachment,
+}
+
+/// A request to live migrate a server.
+#[derive(Debug)]
+pub struct LiveMigrateBuilder<'server> {
+    server: &'server mut Server,
+    inner: protocol::LiveMigrate,
+}
+
 /// A virtual NIC of a new server.
 #[derive(Clone, Debug)]
 pub enum ServerNIC {
@@ -80,8 +98,14 @@ pub enum ServerNIC {
     FromNetwork(NetworkRef),
     /// A NIC with the given port.
     WithPort(PortRef),
-    /// A NIC with the given fixed IP.
+    /// A NIC with the given fixed IPv4 address.
+    ///
+    /// Use on IPv4-only networks or to pick the IPv4 address on dual-stack ones.
     WithFixedIp(Ipv4Addr),
+    /// A NIC with the given fixed IPv6 address.
+    ///
+    /// Use on IPv6-only or dual-stack networks.
+    WithFixedIpv6(Ipv6Addr),
 }
 
 /// A request to create a server.
@@ -110,6 +134,8 @@ impl Refresh for Server {
     /// Refresh the server.
     fn refresh(&mut self) -> Result<()> {
         self.inner = api::get_server_by_id(&self.session, &self.inner.id)?;
+        self.server_group = ValueCache::new(None);
+        self.dirty.clear();
         Ok(())
     }
 }
@@ -118,7 +144,16 @@ impl Server {
     /// Create a new Server object.
     pub(crate) fn new(session: Rc<Session>, inner: protocol::Server) -> Result<Server> {
         let flavor = api::get_flavor(&session, &inner.flavor.id)?;
-        Ok(Server {
+        Ok(Server::with_flavor(session, inner, flavor))
+    }
+
+    /// Create a new Server object from an already fetched flavor.
+    fn with_flavor(
+        session: Rc<Session>,
+        inner: protocol::Server,
+        flavor: protocol::Flavor,
+    ) -> Server {
+        Server {
             session,
             inner,
             flavor: protocol::ServerFlavor {
@@ -130,7 +165,9 @@ impl Server {
                 swap_size: flavor.swap,
                 vcpu_count: flavor.vcpus,
             },
-        })
+            server_group: ValueCache::new(None),
+            dirty: HashSet::new(),
+        }
     }
 
     /// Load a Server object.
@@ -139,6 +176,55 @@ impl Server {
         Server::new(session, inner)
     }
 
+    /// Load several Server objects concurrently.
+    ///
+    /// Every server is fetched in its own thread, at most `BATCH_SIZE` at once.
+    /// Flavors are fetched afterwards, once per distinct flavor. The results
+    /// are in the same order as the IDs.
+    pub(crate) fn load_many<Id: AsRef<str>>(
+        session: Rc<Session>,
+        ids: &[Id],
+    ) -> Vec<Result<Server>> {
+        const BATCH_SIZE: usize = 16;
+
+        let mut servers = Vec::with_capacity(ids.len());
+        for batch in ids.chunks(BATCH_SIZE) {
+            let handles: Vec<_> = batch
+                .iter()
+                .map(|id| {
+                    let async_session = session.session().clone();
+                    let id = id.as_ref().to_string();
+                    thread::spawn(move || {
+                        let session = Session::new(async_session);
+                        api::get_server(&session, id)
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                servers.push(handle.join().unwrap_or_else(|_| {
+                    Err(Error::new(
+                        ErrorKind::OperationFailed,
+                        "Thread fetching a server panicked",
+                    ))
+                }));
+            }
+        }
+
+        let mut flavors: HashMap<String, Result<protocol::Flavor>> = HashMap::new();
+        servers
+            .into_iter()
+            .map(|server| {
+                let inner = server?;
+                let flavor = flavors
+                    .entry(inner.flavor.id.clone())
+                    .or_insert_with(|| api::get_flavor(&session, &inner.flavor.id))
+                    .clone()?;
+                Ok(Server::with_flavor(session.clone(), inner, flavor))
+            })
+            .collect()
+    }
+
     transparent_property! {
         #[doc = "IPv4 address to access the server (if provided)."]
         access_ipv4: Option<Ipv4Addr>
@@ -154,6 +240,69 @@ impl Server {
         addresses: ref HashMap<String, Vec<protocol::ServerAddress>>
     }
 
+    /// All fixed IPs of the server.
+    ///
+    /// Addresses with an unknown type are considered fixed.
+    pub fn all_fixed_ips(&self) -> Vec<IpAddr> {
+        self.inner
+            .addresses
+            .values()
+            .flat_map(|l| l.iter())
+            .filter(|a| a.addr_type != Some(protocol::AddressType::Floating))
+            .map(|a| a.addr)
+            .collect()
+    }
+
+    /// Associate a floating IP with the server.
+    ///
+    /// A free floating IP from `floating_network` is used, a new one is
+    /// allocated if there are none. It is associated with the first port of
+    /// the server on `port_network`, or with the first port of the server if
+    /// `port_network` is `None`. A newly allocated floating IP is released
+    /// if the association fails.
+    #[cfg(feature = "network")]
+    pub fn associate_floating_ip<N>(
+        &self,
+        floating_network: N,
+        port_network: Option<NetworkRef>,
+    ) -> Result<FloatingIp>
+    where
+        N: Into<NetworkRef>,
+    {
+        let mut query = PortQuery::new(self.session.clone()).with_server(self);
+        if let Some(network) = port_network {
+            query.set_network(network);
+        }
+        let port = query.into_iter().next()?.ok_or_else(|| {
+            Error::new(
+                ErrorKind::ResourceNotFound,
+                "No suitable port found for the server",
+            )
+        })?;
+
+        let floating_network = floating_network.into();
+        let (mut floating_ip, allocated) =
+            match FloatingIp::find_free(self.session.clone(), floating_network.clone())? {
+                Some(floating_ip) => (floating_ip, false),
+                None => (
+                    NewFloatingIp::new(self.session.clone(), floating_network).create()?,
+                    true,
+                ),
+            };
+
+        if let Err(err) = floating_ip.associate(&port, None) {
+            if allocated {
+                let id = floating_ip.id().clone();
+                if let Err(delete_err) = floating_ip.delete() {
+                    warn!("Failed to release floating IP {}: {}", id, delete_err);
+                }
+            }
+            return Err(err);
+        }
+
+        Ok(floating_ip)
+    }
+
     transparent_property! {
         #[doc = "Availability zone."]
         availability_zone: ref String
@@ -169,12 +318,57 @@ impl Server {
         description: ref Option<String>
     }
 
+    /// Update the description (requires microversion 2.19).
+    ///
+    /// Use `None` to remove the description.
+    #[allow(unused_results)]
+    pub fn set_description(&mut self, value: Option<&str>) {
+        self.inner.description = value.map(String::from);
+        self.dirty.insert("description");
+    }
+
+    /// Update the description (requires microversion 2.19).
+    ///
+    /// Use `None` to remove the description.
+    #[inline]
+    pub fn with_description(mut self, value: Option<&str>) -> Self {
+        self.set_description(value);
+        self
+    }
+
+    /// A field returned by the Compute API that has no dedicated accessor.
+    ///
+    /// Useful for fields added by new microversions or extensions.
+    pub fn extra_field(&self, key: &str) -> Option<&serde_json::Value> {
+        self.inner.extra.get(key)
+    }
+
     /// Flavor information used to create this server.
     #[inline]
     pub fn flavor(&self) -> &protocol::ServerFlavor {
         &self.flavor
     }
 
+    /// Get a URL of a remote console of the server.
+    ///
+    /// The console must be enabled for the server's hypervisor.
+    pub fn get_console_url(&self, console_type: protocol::ConsoleType) -> Result<String> {
+        api::get_console_url(&self.session, &self.inner.id, console_type)
+    }
+
+    /// Get an action performed on the server by the ID of its request.
+    ///
+    /// Unlike `list_actions`, the result includes the events of the action.
+    pub fn get_action<R: AsRef<str>>(&self, request_id: R) -> Result<protocol::InstanceAction> {
+        api::get_server_action(&self.session, &self.inner.id, request_id)
+    }
+
+    /// Get the attachment of the interface with the given port ID.
+    pub fn get_interface<P: AsRef<str>>(&self, port_id: P) -> Result<InterfaceAttachment> {
+        let inner = api::get_server_interface(&self.session, &self.inner.id, port_id)?;
+        Ok(InterfaceAttachment { inner })
+    }
+
     /// Find a floating IP, if it exists.
     ///
     /// If multiple floating IPs exist, the first is returned.
@@ -246,6 +440,13 @@ impl Server {
         }
     }
 
+    /// List actions performed on the server, most recent first.
+    ///
+    /// Events are not included, use `get_action` to fetch them.
+    pub fn list_actions(&self) -> Result<Vec<protocol::InstanceAction>> {
+        api::list_server_actions(&self.session, &self.inner.id)
+    }
+
     transparent_property! {
         #[doc = "Name of a key pair used with this server (if any)."]
         key_pair_name: ref Option<String>
@@ -256,16 +457,71 @@ impl Server {
         name: ref String
     }
 
+    update_field! {
+        #[doc = "Update the server name."]
+        set_name, with_name -> name: String
+    }
+
     transparent_property! {
         #[doc = "Metadata associated with the server."]
         metadata: ref HashMap<String, String>
     }
 
+    /// NUMA topology of the server, if it has one.
+    ///
+    /// The topology reported by the Compute service (API version 2.78 and
+    /// newer) is preferred, host-specific fields are only visible to
+    /// administrators. On older clouds the topology requested by the
+    /// `hw:numa_*` extra specs of the flavor is returned instead.
+    pub fn numa_topology(&self) -> Result<Option<protocol::NumaTopology>> {
+        if api::supports_server_topology(&self.session)? {
+            let topology = api::get_server_topology(&self.session, &self.inner.id)?;
+            Ok(if topology.nodes.is_empty() {
+                None
+            } else {
+                Some(topology)
+            })
+        } else {
+            Ok(self.flavor.numa_topology())
+        }
+    }
+
+    /// Fetch the ports attached to the server.
+    #[cfg(feature = "network")]
+    pub fn ports(&self) -> Result<Vec<Port>> {
+        PortQuery::new(self.session.clone()).with_server(self).all()
+    }
+
     transparent_property! {
         #[doc = "Server power state."]
         power_state: protocol::ServerPowerState
     }
 
+    /// Get a URL of the SPICE (HTML5) console of the server.
+    ///
+    /// A shortcut for `get_console_url(ConsoleType::Spice)`.
+    pub fn spice_console(&self) -> Result<String> {
+        self.get_console_url(protocol::ConsoleType::Spice)
+    }
+
+    /// Find the server group this server belongs to (if any).
+    ///
+    /// Nova does not report the group of a server, so this call lists
+    /// server groups and checks their members. The result is cached until
+    /// the server is refreshed.
+    pub fn server_group(&self) -> Result<Option<ServerGroup>> {
+        self.server_group.ensure_value(|| {
+            let mut iter = ServerGroupQuery::new(self.session.clone()).into_iter();
+            while let Some(group) = iter.next()? {
+                if group.contains(self) {
+                    return Ok(Some(group));
+                }
+            }
+            Ok(None)
+        })?;
+        Ok(self.server_group.extract(Clone::clone).flatten())
+    }
+
     transparent_property! {
         #[doc = "Server status."]
         status: protocol::ServerStatus
@@ -276,6 +532,20 @@ impl Server {
         updated_at: DateTime<FixedOffset>
     }
 
+    /// Add a fixed IP from the given network to the server.
+    ///
+    /// # Deprecation
+    ///
+    /// This uses the `addFixedIp` action, which was removed in Compute API 2.44
+    /// and is only honoured by legacy clouds. On newer clouds create a port on
+    /// the network and attach it to the server instead.
+    pub fn add_fixed_ip_legacy<N: Into<NetworkRef>>(&self, network: N) -> Result<()> {
+        let mut args = HashMap::new();
+        let _ = args.insert("networkId", network.into().into_verified(&self.session)?);
+        api::server_action_with_args(&self.session, &self.inner.id, "addFixedIp", args)
+            .map_err(legacy_fixed_ip_error)
+    }
+
     /// Delete the server.
     pub fn delete(self) -> Result<DeletionWaiter<Server>> {
         api::delete_server(&self.session, &self.inner.id)?;
@@ -286,6 +556,40 @@ impl Server {
         ))
     }
 
+    /// Force deletion of the server, skipping soft deletion.
+    ///
+    /// Only usable by administrators by default. Intended for servers that
+    /// are soft-deleted or stuck in deletion, otherwise use `delete`.
+    pub fn force_delete(self) -> Result<()> {
+        api::server_simple_action(&self.session, &self.inner.id, "forceDelete")
+    }
+
+    /// Whether the server is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Prepare a live migration of the server.
+    ///
+    /// By default the Compute service picks the destination host and decides
+    /// on block migration itself.
+    pub fn live_migrate_builder(&mut self) -> LiveMigrateBuilder<'_> {
+        LiveMigrateBuilder {
+            server: self,
+            inner: protocol::LiveMigrate::default(),
+        }
+    }
+
+    /// Live migrate the server to the given host.
+    ///
+    /// A shortcut for `live_migrate_builder().with_host(host).execute()`.
+    pub fn live_migrate_to_host<'server, H: Into<String>>(
+        &'server mut self,
+        host: H,
+    ) -> Result<ServerStatusWaiter<'server>> {
+        self.live_migrate_builder().with_host(host).execute()
+    }
+
     /// Reboot the server.
     pub fn reboot<'server>(
         &'server mut self,
@@ -300,6 +604,60 @@ impl Server {
         })
     }
 
+    /// Dissociate all floating IPs from the ports of this server.
+    ///
+    /// Returns the floating IPs that were dissociated, so that they can be
+    /// released or reassigned, together with the errors for those that could
+    /// not be. A failure to dissociate one floating IP does not prevent
+    /// processing the remaining ones.
+    #[cfg(feature = "network")]
+    pub fn remove_all_floating_ips(&self) -> Result<(Vec<FloatingIp>, Vec<Error>)> {
+        let mut removed = Vec::new();
+        let mut errors = Vec::new();
+        for port in self.ports()? {
+            let floating_ips = FloatingIpQuery::new(self.session.clone())
+                .with_port(port)
+                .all()?;
+            for mut floating_ip in floating_ips {
+                match floating_ip.dissociate() {
+                    Ok(()) => removed.push(floating_ip),
+                    Err(err) => errors.push(err),
+                }
+            }
+        }
+        Ok((removed, errors))
+    }
+
+    /// Remove a fixed IP from the server.
+    ///
+    /// # Deprecation
+    ///
+    /// This uses the `removeFixedIp` action, which was removed in Compute API
+    /// 2.44 and is only honoured by legacy clouds. On newer clouds detach the
+    /// port holding the IP address from the server instead.
+    pub fn remove_fixed_ip_legacy(&self, ip: IpAddr) -> Result<()> {
+        let mut args = HashMap::new();
+        let _ = args.insert("address", ip);
+        api::server_action_with_args(&self.session, &self.inner.id, "removeFixedIp", args)
+            .map_err(legacy_fixed_ip_error)
+    }
+
+    /// Restore a soft-deleted server.
+    pub fn restore(&mut self) -> Result<()> {
+        api::server_simple_action(&self.session, &self.inner.id, "restore")?;
+        self.refresh()
+    }
+
+    /// Save the changes to the server.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::ServerUpdate::default();
+        save_fields! {
+            self -> update: description name
+        };
+        api::update_server(&self.session, self.id(), update)?;
+        self.refresh()
+    }
+
     /// Start the server, optionally wait for it to be active.
     pub fn start<'server>(&'server mut self) -> Result<ServerStatusWaiter<'server>> {
         api::server_simple_action(&self.session, &self.inner.id, "os-start")?;
@@ -373,7 +731,90 @@ impl<'server> WaiterCurrentState<Server> for ServerStatusWaiter<'server> {
     }
 }
 
+impl InterfaceAttachment {
+    transparent_property! {
+        #[doc = "Fixed IP addresses of the interface."]
+        fixed_ips: ref Vec<protocol::InterfaceFixedIp>
+    }
+
+    /// MAC address of the interface.
+    pub fn mac_address(&self) -> &String {
+        &self.inner.mac_addr
+    }
+
+    /// ID of the network of the interface.
+    pub fn network_id(&self) -> &String {
+        &self.inner.net_id
+    }
+
+    transparent_property! {
+        #[doc = "ID of the port of the interface."]
+        port_id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Status of the port (e.g. `ACTIVE`)."]
+        port_state: ref String
+    }
+}
+
+impl<'server> LiveMigrateBuilder<'server> {
+    /// Set the destination host.
+    pub fn set_host<H: Into<String>>(&mut self, host: H) {
+        self.inner.host = Some(host.into());
+    }
+
+    /// Set the destination host.
+    pub fn with_host<H: Into<String>>(mut self, host: H) -> Self {
+        self.set_host(host);
+        self
+    }
+
+    /// Set the block migration mode.
+    ///
+    /// The default `Auto` mode requires compute API version 2.25.
+    pub fn set_block_migration(&mut self, mode: protocol::BlockMigrationMode) {
+        self.inner.block_migration = mode;
+    }
+
+    /// Set the block migration mode.
+    pub fn with_block_migration(mut self, mode: protocol::BlockMigrationMode) -> Self {
+        self.set_block_migration(mode);
+        self
+    }
+
+    /// Whether to allow disk over-commit on the destination host.
+    ///
+    /// Ignored by compute API version 2.25 and newer.
+    pub fn set_disk_over_commit(&mut self, value: bool) {
+        self.inner.disk_over_commit = Some(value);
+    }
+
+    /// Whether to allow disk over-commit on the destination host.
+    pub fn with_disk_over_commit(mut self, value: bool) -> Self {
+        self.set_disk_over_commit(value);
+        self
+    }
+
+    /// Start the live migration, optionally wait for it to finish.
+    ///
+    /// The server becomes active again when the migration is over, even if it
+    /// failed and the server stayed on its original host.
+    pub fn execute(self) -> Result<ServerStatusWaiter<'server>> {
+        api::live_migrate_server(&self.server.session, &self.server.inner.id, self.inner)?;
+        Ok(ServerStatusWaiter {
+            server: self.server,
+            target: protocol::ServerStatus::Active,
+        })
+    }
+}
+
 impl ServerSummary {
+    /// Create a new ServerSummary object.
+    pub(crate) fn new(session: Rc<Session>, inner: IdAndName) -> ServerSummary {
+        ServerSummary { session, inner }
+    }
+
     transparent_property! {
         #[doc = "Server unique ID."]
         id: ref String
@@ -451,6 +892,14 @@ impl ServerQuery {
         set_flavor, with_flavor -> flavor: FlavorRef
     }
 
+    query_filter! {
+        #[doc = "Filter by the compute host the server is running on."]
+        #[doc = ""]
+        #[doc = "This is an admin-only filter. Some clouds silently ignore it or"]
+        #[doc = "return no results for regular users."]
+        set_host, with_host -> host: String
+    }
+
     query_filter! {
         #[doc = "Filter by host name."]
         set_hostname, with_hostname -> hostname: String
@@ -476,6 +925,14 @@ impl ServerQuery {
         set_name, with_name -> name: String
     }
 
+    query_filter! {
+        #[doc = "Filter by the hypervisor node the server is running on."]
+        #[doc = ""]
+        #[doc = "This is an admin-only filter. Some clouds silently ignore it or"]
+        #[doc = "return no results for regular users."]
+        set_node, with_node -> node: String
+    }
+
     query_filter! {
         #[doc = "Filter by project (also commonly known as tenant)."]
         set_project, with_project -> project_id: ProjectRef
@@ -553,10 +1010,7 @@ impl ResourceQuery for ServerQuery {
         let query = self.query.with_marker_and_limit(limit, marker);
         Ok(api::list_servers(&self.session, &query)?
             .into_iter()
-            .map(|srv| ServerSummary {
-                session: self.session.clone(),
-                inner: srv,
-            })
+            .map(|srv| ServerSummary::new(self.session.clone(), srv))
             .collect())
     }
 }
@@ -612,6 +1066,20 @@ impl From<ServerQuery> for DetailedServerQuery {
     }
 }
 
+fn legacy_fixed_ip_error(err: Error) -> Error {
+    match err.kind() {
+        ErrorKind::InvalidInput | ErrorKind::ResourceNotFound => Error::new(
+            err.kind(),
+            format!(
+                "{} (the cloud may not support legacy fixed IP actions, \
+                 use ports attached to the server instead)",
+                err
+            ),
+        ),
+        _ => err,
+    }
+}
+
 fn convert_networks(
     session: &Session,
     networks: Vec<ServerNIC>,
@@ -625,7 +1093,12 @@ fn convert_networks(
             ServerNIC::WithPort(p) => protocol::ServerNetwork::Port {
                 port: p.into_verified(session)?.into(),
             },
-            ServerNIC::WithFixedIp(ip) => protocol::ServerNetwork::FixedIp { fixed_ip: ip },
+            ServerNIC::WithFixedIp(ip) => protocol::ServerNetwork::FixedIp {
+                fixed_ip: ip.into(),
+            },
+            ServerNIC::WithFixedIpv6(ip) => protocol::ServerNetwork::FixedIp {
+                fixed_ip: ip.into(),
+            },
         });
     }
     Ok(result)
@@ -682,6 +1155,12 @@ impl NewServer {
         self.nics.push(ServerNIC::WithFixedIp(fixed_ip));
     }
 
+    /// Add a virtual NIC with given fixed IPv6 address to the new server.
+    #[inline]
+    pub fn add_fixed_ipv6(&mut self, fixed_ip: Ipv6Addr) {
+        self.nics.push(ServerNIC::WithFixedIpv6(fixed_ip));
+    }
+
     /// Add a virtual NIC from this network to the new server.
     #[inline]
     pub fn add_network<N>(&mut self, network: N)
@@ -765,6 +1244,13 @@ impl NewServer {
         self
     }
 
+    /// Add a virtual NIC with given fixed IPv6 address to the new server.
+    #[inline]
+    pub fn with_fixed_ipv6(mut self, fixed_ip: Ipv6Addr) -> NewServer {
+        self.add_fixed_ipv6(fixed_ip);
+        self
+    }
+
     /// Use this image as a source for the new server.
     #[inline]
     pub fn with_image<I>(mut self, image: I) -> NewServer
@@ -0,0 +1,42 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Foundation bits exposing the Identity API.
+
+use osauth::services::{GenericService, VersionSelector};
+
+use super::super::session::Session;
+use super::super::Result;
+use super::protocol::*;
+
+pub(crate) const IDENTITY: GenericService =
+    GenericService::new("identity", VersionSelector::Major(3));
+
+/// List services together with their endpoints.
+pub fn list_services(session: &Session) -> Result<Vec<ServiceEntry>> {
+    trace!("Listing identity services");
+    let mut root: ServicesRoot = session.get_json(IDENTITY, &["services"], None)?;
+    let endpoints: EndpointsRoot = session.get_json(IDENTITY, &["endpoints"], None)?;
+    for endpoint in endpoints.endpoints {
+        if let Some(service) = root
+            .services
+            .iter_mut()
+            .find(|service| service.id == endpoint.service_id)
+        {
+            service.endpoints.push(endpoint);
+        }
+    }
+    trace!("Received services: {:?}", root.services);
+    Ok(root.services)
+}
@@ -0,0 +1,29 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Identity API implementation bits.
+
+mod api;
+mod protocol;
+
+use super::common::{IntoVerified, ProjectRef, UserRef};
+
+pub(crate) use self::api::{list_services, IDENTITY};
+pub use self::protocol::{EndpointEntry, ServiceEntry};
+
+// Projects and users are not managed by this crate yet, so references to them
+// are passed to other services as they are.
+impl IntoVerified for ProjectRef {}
+
+impl IntoVerified for UserRef {}
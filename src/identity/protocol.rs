@@ -0,0 +1,82 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON structures and protocol bits for the Identity API.
+
+#![allow(missing_docs)]
+
+use serde::Deserialize;
+
+/// An endpoint of a service in the catalog.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EndpointEntry {
+    /// Whether the endpoint is enabled.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Unique ID.
+    pub id: String,
+    /// Interface of the endpoint (`public`, `internal` or `admin`).
+    pub interface: String,
+    /// Region of the endpoint (if any).
+    #[serde(default)]
+    pub region_id: Option<String>,
+    /// ID of the service this endpoint belongs to.
+    pub service_id: String,
+    /// URL of the endpoint.
+    pub url: String,
+}
+
+/// A service in the catalog.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServiceEntry {
+    /// Service description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Whether the service is enabled.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Endpoints of the service.
+    #[serde(skip)]
+    pub endpoints: Vec<EndpointEntry>,
+    /// Unique ID.
+    pub id: String,
+    /// Service name (if any).
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Service type, e.g. `compute`.
+    #[serde(rename = "type")]
+    pub service_type: String,
+}
+
+impl ServiceEntry {
+    /// Whether the service is enabled and has at least one enabled endpoint.
+    pub fn is_available(&self) -> bool {
+        self.enabled && self.endpoints.iter().any(|endpoint| endpoint.enabled)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EndpointsRoot {
+    pub endpoints: Vec<EndpointEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServicesRoot {
+    pub services: Vec<ServiceEntry>,
+}
+
+#[inline]
+fn default_enabled() -> bool {
+    true
+}
@@ -17,11 +17,13 @@
 use std::fmt::Debug;
 
 use osauth::services::IMAGE;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Method;
 use serde::Serialize;
 
 use super::super::session::Session;
 use super::super::utils::{self, ResultExt};
-use super::super::Result;
+use super::super::{Error, ErrorKind, Result};
 use super::protocol::*;
 
 /// Get an image.
@@ -62,3 +64,25 @@ pub fn list_images<Q: Serialize + Sync + Debug>(
     trace!("Received images: {:?}", root.images);
     Ok(root.images)
 }
+
+/// Update an image using JSON patch.
+pub fn update_image<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    patch: Vec<ImagePatch>,
+) -> Result<Image> {
+    debug!("Updating image {} with {:?}", id.as_ref(), patch);
+    let body = serde_json::to_vec(&patch).map_err(|err| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Cannot serialize image patch: {}", err),
+        )
+    })?;
+    let request = session
+        .request(IMAGE, Method::PATCH, &["images", id.as_ref()], None)?
+        .header(CONTENT_TYPE, "application/openstack-images-v2.1-json-patch")
+        .body(body);
+    let image: Image = session.fetch_json(request)?;
+    debug!("Updated image {}", id.as_ref());
+    Ok(image)
+}
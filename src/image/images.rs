@@ -14,6 +14,7 @@
 
 //! Image management via Image API.
 
+use std::io::Read;
 use std::rc::Rc;
 
 use chrono::{DateTime, FixedOffset};
@@ -22,7 +23,7 @@ use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
 use super::super::common::{ImageRef, IntoVerified, Refresh, ResourceIterator, ResourceQuery};
 use super::super::session::Session;
 use super::super::utils::Query;
-use super::super::{Error, Result, Sort};
+use super::super::{Error, ErrorKind, Result, Sort};
 use super::{api, protocol};
 
 /// A query to image list.
@@ -73,6 +74,16 @@ impl Image {
         disk_format: Option<protocol::ImageDiskFormat>
     }
 
+    /// Algorithm used for the secure hash of the image (e.g. `sha512`).
+    pub fn hash_algo(&self) -> Option<&String> {
+        self.inner.os_hash_algo.as_ref()
+    }
+
+    /// Secure hash of the image data, see `hash_algo`.
+    pub fn hash_value(&self) -> Option<&String> {
+        self.inner.os_hash_value.as_ref()
+    }
+
     transparent_property! {
         #[doc = "Unique ID."]
         id: ref String
@@ -97,6 +108,11 @@ impl Image {
         name: ref String
     }
 
+    /// ID of the server this image is a snapshot of (if any).
+    pub fn server_id(&self) -> Option<&String> {
+        self.inner.instance_uuid.as_ref()
+    }
+
     transparent_property! {
         #[doc = "Image size in bytes."]
         size: Option<u64>
@@ -121,6 +137,79 @@ impl Image {
         #[doc = "Image visibility."]
         visibility: protocol::ImageVisibility
     }
+
+    /// Remove a property from the image.
+    pub fn delete_property<K: AsRef<str>>(&mut self, key: K) -> Result<()> {
+        let patch = protocol::ImagePatch::Remove {
+            path: protocol::ImagePatch::property_path(key),
+        };
+        self.inner = api::update_image(&self.session, &self.inner.id, vec![patch])?;
+        Ok(())
+    }
+
+    /// Add or replace a property of the image.
+    pub fn set_property<K, V>(&mut self, key: K, value: V) -> Result<()>
+    where
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        let patch = protocol::ImagePatch::Add {
+            path: protocol::ImagePatch::property_path(key),
+            value: value.into(),
+        };
+        self.inner = api::update_image(&self.session, &self.inner.id, vec![patch])?;
+        Ok(())
+    }
+
+    /// Change the visibility of the image.
+    pub fn set_visibility(&mut self, visibility: protocol::ImageVisibility) -> Result<()> {
+        let patch = protocol::ImagePatch::Replace {
+            path: "/visibility".into(),
+            value: visibility.into(),
+        };
+        self.inner = api::update_image(&self.session, &self.inner.id, vec![patch])?;
+        Ok(())
+    }
+
+    /// Verify the image data against its MD5 checksum.
+    ///
+    /// Fails with `ResourceNotFound` if the image has no checksum.
+    pub fn verify_checksum(&self, data: &[u8]) -> Result<bool> {
+        self.verify_stream_checksum(data)
+    }
+
+    /// Verify the image data read from the reader against its MD5 checksum.
+    ///
+    /// Fails with `ResourceNotFound` if the image has no checksum.
+    pub fn verify_stream_checksum<R: Read>(&self, mut reader: R) -> Result<bool> {
+        let expected = match self.inner.checksum {
+            Some(ref checksum) => checksum,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::ResourceNotFound,
+                    format!("Image {} has no checksum", self.inner.id),
+                ))
+            }
+        };
+
+        let mut context = md5::Context::new();
+        let mut buffer = [0; 65536];
+        loop {
+            let size = reader.read(&mut buffer).map_err(|err| {
+                Error::new(
+                    ErrorKind::OperationFailed,
+                    format!("Cannot read data of image {}: {}", self.inner.id, err),
+                )
+            })?;
+            if size == 0 {
+                break;
+            }
+            context.consume(&buffer[..size]);
+        }
+
+        let actual = format!("{:x}", context.compute());
+        Ok(actual.eq_ignore_ascii_case(expected))
+    }
 }
 
 impl Refresh for Image {
@@ -166,6 +255,13 @@ impl ImageQuery {
         self
     }
 
+    query_filter! {
+        #[doc = "Filter by member status of shared images."]
+        #[doc = ""]
+        #[doc = "Only accepted shared images are returned by default."]
+        with_member_status -> member_status: protocol::ImageMemberStatus
+    }
+
     query_filter! {
         #[doc = "Filter by image name."]
         with_name -> name
@@ -261,6 +357,12 @@ impl From<Image> for ImageRef {
     }
 }
 
+impl<'a> From<&'a Image> for ImageRef {
+    fn from(value: &'a Image) -> ImageRef {
+        ImageRef::new_verified(value.inner.id.clone())
+    }
+}
+
 #[cfg(feature = "image")]
 impl IntoVerified for ImageRef {
     /// Verify this reference and convert to an ID, if possible.
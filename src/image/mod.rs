@@ -20,5 +20,6 @@ mod protocol;
 
 pub use self::images::{Image, ImageQuery};
 pub use self::protocol::{
-    ImageContainerFormat, ImageDiskFormat, ImageSortKey, ImageStatus, ImageVisibility,
+    ImageContainerFormat, ImageDiskFormat, ImageMemberStatus, ImageSortKey, ImageStatus,
+    ImageVisibility,
 };
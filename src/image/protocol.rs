@@ -19,7 +19,7 @@
 
 use chrono::{DateTime, FixedOffset};
 use reqwest::Url;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::super::common;
 
@@ -46,6 +46,17 @@ protocol_enum! {
     }
 }
 
+protocol_enum! {
+    #[doc = "Possible statuses of an image membership."]
+    enum ImageMemberStatus {
+        Pending = "pending",
+        Accepted = "accepted",
+        Rejected = "rejected",
+        #[doc = "Only valid as a query filter, matches any member status."]
+        All = "all"
+    }
+}
+
 protocol_enum! {
     #[doc = "Possible container formats."]
     enum ImageContainerFormat {
@@ -108,11 +119,17 @@ pub struct Image {
     pub disk_format: Option<ImageDiskFormat>,
     pub id: String,
     #[serde(default)]
+    pub instance_uuid: Option<String>,
+    #[serde(default)]
     pub min_disk: u32,
     #[serde(default)]
     pub min_ram: u32,
     pub name: String,
     #[serde(default)]
+    pub os_hash_algo: Option<String>,
+    #[serde(default)]
+    pub os_hash_value: Option<String>,
+    #[serde(default)]
     pub size: Option<u64>,
     pub status: ImageStatus,
     pub updated_at: DateTime<FixedOffset>,
@@ -121,8 +138,48 @@ pub struct Image {
     pub visibility: ImageVisibility,
 }
 
+/// A single JSON patch (RFC 6902) operation on an image.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum ImagePatch {
+    Add { path: String, value: String },
+    Remove { path: String },
+    Replace { path: String, value: String },
+}
+
+impl ImagePatch {
+    /// JSON pointer to an image property.
+    pub fn property_path<S: AsRef<str>>(key: S) -> String {
+        format!("/{}", key.as_ref().replace('~', "~0").replace('/', "~1"))
+    }
+}
+
 /// A list of images.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ImagesRoot {
     pub images: Vec<Image>,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use super::ImagePatch;
+
+    #[test]
+    fn test_image_patch_serialization() {
+        let patch = vec![
+            ImagePatch::Replace {
+                path: "/visibility".into(),
+                value: "shared".into(),
+            },
+            ImagePatch::Remove {
+                path: ImagePatch::property_path("a/b~c"),
+            },
+        ];
+        assert_eq!(
+            serde_json::to_string(&patch).unwrap(),
+            r#"[{"op":"replace","path":"/visibility","value":"shared"},{"op":"remove","path":"/a~1b~0c"}]"#
+        );
+    }
+}
@@ -427,6 +427,7 @@ macro_rules! update_field {
 
     ($(#[$attr:meta])* $set_func:ident, $with_func:ident -> $name:ident) => (
         $(#[$attr])*
+        #[allow(unused_results)]
         pub fn $set_func<S: Into<String>>(&mut self, value: S)  {
             self.inner.$name = value.into();
             self.dirty.insert(stringify!($name));
@@ -626,6 +627,21 @@ macro_rules! protocol_enum {
             }
         }
 
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::Error;
+
+            /// Parse the wire format representation, ignoring case.
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                $(if s.eq_ignore_ascii_case($val) {
+                    return Ok($name::$item);
+                })+
+                Err($crate::Error::new(
+                    $crate::ErrorKind::InvalidInput,
+                    format!("Unexpected {}: {}", stringify!($name), s),
+                ))
+            }
+        }
+
         impl ::serde::ser::Serialize for $name {
             fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
                     where S: ::serde::ser::Serializer {
@@ -655,6 +671,8 @@ mod cloud;
 pub mod common;
 #[cfg(feature = "compute")]
 pub mod compute;
+#[cfg(feature = "identity")]
+pub mod identity;
 #[cfg(feature = "image")]
 pub mod image;
 #[cfg(feature = "network")]
@@ -675,7 +693,7 @@ pub use osauth::sync::Result;
 pub use osauth::{EndpointFilters, Error, ErrorKind, InterfaceType, ValidInterfaces};
 
 pub use crate::cloud::Cloud;
-pub use crate::common::Refresh;
+pub use crate::common::{Refresh, Taggable};
 
 /// Sorting request.
 #[derive(Debug, Clone)]
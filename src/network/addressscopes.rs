@@ -0,0 +1,330 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Address scope management via Network API.
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    AddressScopeRef, DeletionWaiter, IntoVerified, Refresh, ResourceIterator, ResourceQuery,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol};
+
+/// A query to address scope list.
+#[derive(Clone, Debug)]
+pub struct AddressScopeQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single address scope.
+#[derive(Clone, Debug)]
+pub struct AddressScope {
+    session: Rc<Session>,
+    inner: protocol::AddressScope,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create an address scope.
+#[derive(Clone, Debug)]
+pub struct NewAddressScope {
+    session: Rc<Session>,
+    inner: protocol::AddressScope,
+}
+
+impl AddressScope {
+    /// Create an address scope object.
+    fn new(session: Rc<Session>, inner: protocol::AddressScope) -> AddressScope {
+        AddressScope {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load an AddressScope object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<AddressScope> {
+        let inner = api::get_address_scope(&session, id)?;
+        Ok(AddressScope::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "IP protocol version."]
+        ip_version: protocol::IpVersion
+    }
+
+    transparent_property! {
+        #[doc = "Address scope name."]
+        name: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Whether the address scope is shared between projects."]
+        shared: bool
+    }
+
+    update_field! {
+        #[doc = "Set whether the address scope is shared between projects."]
+        set_shared, with_shared -> shared: bool
+    }
+
+    /// Delete the address scope.
+    pub fn delete(self) -> Result<DeletionWaiter<AddressScope>> {
+        api::delete_address_scope(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the address scope is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the address scope.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::AddressScopeUpdate::default();
+        save_fields! {
+            self -> update: name shared
+        };
+        let inner = api::update_address_scope(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for AddressScope {
+    /// Refresh the address scope.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_address_scope_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl AddressScopeQuery {
+    pub(crate) fn new(session: Rc<Session>) -> AddressScopeQuery {
+        AddressScopeQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by IP protocol version.
+    pub fn set_ip_version(&mut self, value: protocol::IpVersion) {
+        self.query.push("ip_version", u8::from(value));
+    }
+
+    /// Filter by IP protocol version.
+    #[inline]
+    pub fn with_ip_version(mut self, value: protocol::IpVersion) -> Self {
+        self.set_ip_version(value);
+        self
+    }
+
+    /// Filter by address scope name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    query_filter! {
+        #[doc = "Filter by whether the address scope is shared."]
+        set_shared, with_shared -> shared: bool
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<AddressScopeQuery> {
+        debug!("Fetching address scopes with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<AddressScope>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<AddressScope> {
+        debug!("Fetching one address scope with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for AddressScopeQuery {
+    type Item = AddressScope;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_address_scopes(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| AddressScope::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl NewAddressScope {
+    /// Start creating an address scope.
+    pub(crate) fn new(
+        session: Rc<Session>,
+        name: String,
+        ip_version: protocol::IpVersion,
+    ) -> NewAddressScope {
+        NewAddressScope {
+            session,
+            inner: protocol::AddressScope {
+                id: String::new(),
+                ip_version,
+                name,
+                project_id: None,
+                shared: false,
+            },
+        }
+    }
+
+    /// Request creation of the address scope.
+    pub fn create(self) -> Result<AddressScope> {
+        let inner = api::create_address_scope(&self.session, self.inner)?;
+        Ok(AddressScope::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the address scope."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the address scope is shared between projects."]
+        set_shared, with_shared -> shared: bool
+    }
+}
+
+impl IntoFallibleIterator for AddressScopeQuery {
+    type Item = AddressScope;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<AddressScopeQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<AddressScope> for AddressScopeRef {
+    fn from(value: AddressScope) -> AddressScopeRef {
+        AddressScopeRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a AddressScope> for AddressScopeRef {
+    fn from(value: &'a AddressScope) -> AddressScopeRef {
+        AddressScopeRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for AddressScopeRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<AddressScopeRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            AddressScopeRef::new_verified(api::get_address_scope(session, &self.value)?.id)
+        })
+    }
+}
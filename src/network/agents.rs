@@ -0,0 +1,250 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Network agent management via Network API.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{Refresh, ResourceIterator, ResourceQuery};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol, BgpSpeaker, Router};
+
+/// A query to network agent list.
+#[derive(Clone, Debug)]
+pub struct NetworkAgentQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single network agent.
+#[derive(Clone, Debug)]
+pub struct NetworkAgent {
+    session: Rc<Session>,
+    inner: protocol::Agent,
+}
+
+impl NetworkAgent {
+    /// Create a network agent object.
+    pub(crate) fn new(session: Rc<Session>, inner: protocol::Agent) -> NetworkAgent {
+        NetworkAgent { session, inner }
+    }
+
+    /// Load a NetworkAgent object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<NetworkAgent> {
+        let inner = api::get_agent(&session, id)?;
+        Ok(NetworkAgent::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "The administrative state of the agent."]
+        admin_state_up: bool
+    }
+
+    transparent_property! {
+        #[doc = "Type of the agent (e.g. `DHCP agent`)."]
+        agent_type: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Whether the agent reports its state in time."]
+        alive: bool
+    }
+
+    transparent_property! {
+        #[doc = "Availability zone of the agent (if available)."]
+        availability_zone: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Name of the executable running the agent."]
+        binary: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Agent-specific configuration."]
+        configurations: ref HashMap<String, serde_json::Value>
+    }
+
+    transparent_property! {
+        #[doc = "Agent description."]
+        description: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Host the agent is running on."]
+        host: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    /// List BGP speakers hosted by the agent (requires administrator rights).
+    pub fn list_bgp_speakers(&self) -> Result<Vec<BgpSpeaker>> {
+        Ok(api::list_agent_bgp_speakers(&self.session, &self.inner.id)?
+            .into_iter()
+            .map(|item| BgpSpeaker::new(self.session.clone(), item))
+            .collect())
+    }
+
+    /// List routers hosted by this L3 agent (requires administrator rights).
+    pub fn list_routers(&self) -> Result<Vec<Router>> {
+        Ok(api::list_agent_l3_routers(&self.session, &self.inner.id)?
+            .into_iter()
+            .map(|item| Router::new(self.session.clone(), item))
+            .collect())
+    }
+
+    transparent_property! {
+        #[doc = "AMQP topic the agent listens on."]
+        topic: ref String
+    }
+}
+
+impl Refresh for NetworkAgent {
+    /// Refresh the agent.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_agent(&self.session, &self.inner.id)?;
+        Ok(())
+    }
+}
+
+impl NetworkAgentQuery {
+    pub(crate) fn new(session: Rc<Session>) -> NetworkAgentQuery {
+        NetworkAgentQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    query_filter! {
+        #[doc = "Filter by agent type (e.g. `L3 agent`)."]
+        set_agent_type, with_agent_type -> agent_type
+    }
+
+    query_filter! {
+        #[doc = "Filter by whether the agent is alive."]
+        set_alive, with_alive -> alive: bool
+    }
+
+    query_filter! {
+        #[doc = "Filter by host."]
+        set_host, with_host -> host
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<NetworkAgentQuery> {
+        debug!("Fetching network agents with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<NetworkAgent>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<NetworkAgent> {
+        debug!("Fetching one network agent with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for NetworkAgentQuery {
+    type Item = NetworkAgent;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_agents(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| NetworkAgent::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl IntoFallibleIterator for NetworkAgentQuery {
+    type Item = NetworkAgent;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<NetworkAgentQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
@@ -20,6 +20,7 @@ use std::fmt::Debug;
 use osauth::services::NETWORK;
 use serde::Serialize;
 
+use super::super::common::protocol;
 use super::super::session::Session;
 use super::super::utils::{self, ResultExt};
 use super::super::{Error, ErrorKind, Result};
@@ -67,6 +68,189 @@ where
     Ok(())
 }
 
+/// Add a gateway network to a BGP speaker.
+pub fn add_bgp_speaker_network<S1, S2>(
+    session: &Session,
+    speaker_id: S1,
+    network_id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Add gateway network {} to BGP speaker {}",
+        network_id.as_ref(),
+        speaker_id.as_ref()
+    );
+    let mut body = HashMap::new();
+    let _ = body.insert("network_id", network_id.as_ref());
+
+    let _ = session.put(
+        NETWORK,
+        &["bgp-speakers", speaker_id.as_ref(), "add_gateway_network"],
+        body,
+        None,
+    )?;
+
+    debug!(
+        "Successfully added gateway network {} to BGP speaker {}",
+        network_id.as_ref(),
+        speaker_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Add a peer to a BGP speaker.
+pub fn add_bgp_speaker_peer<S1, S2>(session: &Session, speaker_id: S1, peer_id: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Add peer {} to BGP speaker {}",
+        peer_id.as_ref(),
+        speaker_id.as_ref()
+    );
+    let mut body = HashMap::new();
+    let _ = body.insert("bgp_peer_id", peer_id.as_ref());
+
+    let _ = session.put(
+        NETWORK,
+        &["bgp-speakers", speaker_id.as_ref(), "add_bgp_peer"],
+        body,
+        None,
+    )?;
+
+    debug!(
+        "Successfully added peer {} to BGP speaker {}",
+        peer_id.as_ref(),
+        speaker_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Schedule a BGP speaker to a dynamic routing agent.
+pub fn add_bgp_speaker_to_dr_agent<S1, S2>(
+    session: &Session,
+    agent_id: S1,
+    speaker_id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Add BGP speaker {} to dynamic routing agent {}",
+        speaker_id.as_ref(),
+        agent_id.as_ref()
+    );
+    let mut body = HashMap::new();
+    let _ = body.insert("bgp_speaker_id", speaker_id.as_ref());
+
+    let _ = session.post(
+        NETWORK,
+        &["agents", agent_id.as_ref(), "bgp-drinstances"],
+        body,
+        None,
+    )?;
+
+    debug!(
+        "Successfully added BGP speaker {} to dynamic routing agent {}",
+        speaker_id.as_ref(),
+        agent_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Schedule a network to a DHCP agent.
+pub fn add_network_to_dhcp_agent<S1, S2>(
+    session: &Session,
+    agent_id: S1,
+    network_id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Add network {} to DHCP agent {}",
+        network_id.as_ref(),
+        agent_id.as_ref()
+    );
+    let mut body = HashMap::new();
+    let _ = body.insert("network_id", network_id.as_ref());
+
+    let _ = session.post(
+        NETWORK,
+        &["agents", agent_id.as_ref(), "dhcp-networks"],
+        body,
+        None,
+    )?;
+
+    debug!(
+        "Successfully added network {} to DHCP agent {}",
+        network_id.as_ref(),
+        agent_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Schedule a router to an L3 agent.
+pub fn add_router_to_l3_agent<S1, S2>(session: &Session, agent_id: S1, router_id: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Add router {} to L3 agent {}",
+        router_id.as_ref(),
+        agent_id.as_ref()
+    );
+    let mut body = HashMap::new();
+    let _ = body.insert("router_id", router_id.as_ref());
+
+    let _ = session.post(
+        NETWORK,
+        &["agents", agent_id.as_ref(), "l3-routers"],
+        body,
+        None,
+    )?;
+
+    debug!(
+        "Successfully added router {} to L3 agent {}",
+        router_id.as_ref(),
+        agent_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Add a tag to a resource.
+pub fn add_tag<S1, S2>(session: &Session, resource_type: &str, id: S1, tag: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Add tag {} to {} {}",
+        tag.as_ref(),
+        resource_type,
+        id.as_ref()
+    );
+    let _ = session.put_empty(
+        NETWORK,
+        &[resource_type, id.as_ref(), "tags", tag.as_ref()],
+        None,
+    )?;
+    debug!(
+        "Successfully added tag {} to {} {}",
+        tag.as_ref(),
+        resource_type,
+        id.as_ref()
+    );
+    Ok(())
+}
+
 /// Add an interface to a router.
 pub fn add_router_interface<S>(
     session: &Session,
@@ -111,6 +295,37 @@ where
     Ok(())
 }
 
+/// Create an address scope.
+pub fn create_address_scope(session: &Session, request: AddressScope) -> Result<AddressScope> {
+    debug!("Creating a new address scope with {:?}", request);
+    let body = AddressScopeRoot {
+        address_scope: request,
+    };
+    let root: AddressScopeRoot = session.post_json(NETWORK, &["address-scopes"], body, None)?;
+    debug!("Created address scope {:?}", root.address_scope);
+    Ok(root.address_scope)
+}
+
+/// Create a BGP peer.
+pub fn create_bgp_peer(session: &Session, request: BgpPeer) -> Result<BgpPeer> {
+    debug!("Creating a new BGP peer with {:?}", request);
+    let body = BgpPeerRoot { bgp_peer: request };
+    let root: BgpPeerRoot = session.post_json(NETWORK, &["bgp-peers"], body, None)?;
+    debug!("Created BGP peer {:?}", root.bgp_peer);
+    Ok(root.bgp_peer)
+}
+
+/// Create a BGP speaker.
+pub fn create_bgp_speaker(session: &Session, request: BgpSpeaker) -> Result<BgpSpeaker> {
+    debug!("Creating a new BGP speaker with {:?}", request);
+    let body = BgpSpeakerRoot {
+        bgp_speaker: request,
+    };
+    let root: BgpSpeakerRoot = session.post_json(NETWORK, &["bgp-speakers"], body, None)?;
+    debug!("Created BGP speaker {:?}", root.bgp_speaker);
+    Ok(root.bgp_speaker)
+}
+
 /// Create a floating IP.
 pub fn create_floating_ip(session: &Session, request: FloatingIp) -> Result<FloatingIp> {
     debug!("Creating a new floating IP with {:?}", request);
@@ -122,6 +337,42 @@ pub fn create_floating_ip(session: &Session, request: FloatingIp) -> Result<Floa
     Ok(root.floatingip)
 }
 
+/// Create a flow classifier.
+pub fn create_flow_classifier(
+    session: &Session,
+    request: FlowClassifier,
+) -> Result<FlowClassifier> {
+    debug!("Creating a new flow classifier with {:?}", request);
+    let body = FlowClassifierRoot {
+        flow_classifier: request,
+    };
+    let root: FlowClassifierRoot =
+        session.post_json(NETWORK, &["sfc", "flow_classifiers"], body, None)?;
+    debug!("Created flow classifier {:?}", root.flow_classifier);
+    Ok(root.flow_classifier)
+}
+
+/// Create an IKE policy.
+pub fn create_ike_policy(session: &Session, request: IkePolicy) -> Result<IkePolicy> {
+    debug!("Creating a new IKE policy with {:?}", request);
+    let body = IkePolicyRoot { ikepolicy: request };
+    let root: IkePolicyRoot = session.post_json(NETWORK, &["vpn", "ikepolicies"], body, None)?;
+    debug!("Created IKE policy {:?}", root.ikepolicy);
+    Ok(root.ikepolicy)
+}
+
+/// Create an IPsec policy.
+pub fn create_ipsec_policy(session: &Session, request: IpsecPolicy) -> Result<IpsecPolicy> {
+    debug!("Creating a new IPsec policy with {:?}", request);
+    let body = IpsecPolicyRoot {
+        ipsecpolicy: request,
+    };
+    let root: IpsecPolicyRoot =
+        session.post_json(NETWORK, &["vpn", "ipsecpolicies"], body, None)?;
+    debug!("Created IPsec policy {:?}", root.ipsecpolicy);
+    Ok(root.ipsecpolicy)
+}
+
 /// Create a network.
 pub fn create_network(session: &Session, request: Network) -> Result<Network> {
     debug!("Creating a new network with {:?}", request);
@@ -140,6 +391,38 @@ pub fn create_port(session: &Session, request: Port) -> Result<Port> {
     Ok(root.port)
 }
 
+/// Create a port chain.
+pub fn create_port_chain(session: &Session, request: PortChain) -> Result<PortChain> {
+    debug!("Creating a new port chain with {:?}", request);
+    let body = PortChainRoot {
+        port_chain: request,
+    };
+    let root: PortChainRoot = session.post_json(NETWORK, &["sfc", "port_chains"], body, None)?;
+    debug!("Created port chain {:?}", root.port_chain);
+    Ok(root.port_chain)
+}
+
+/// Create a port pair.
+pub fn create_port_pair(session: &Session, request: PortPair) -> Result<PortPair> {
+    debug!("Creating a new port pair with {:?}", request);
+    let body = PortPairRoot { port_pair: request };
+    let root: PortPairRoot = session.post_json(NETWORK, &["sfc", "port_pairs"], body, None)?;
+    debug!("Created port pair {:?}", root.port_pair);
+    Ok(root.port_pair)
+}
+
+/// Create a port pair group.
+pub fn create_port_pair_group(session: &Session, request: PortPairGroup) -> Result<PortPairGroup> {
+    debug!("Creating a new port pair group with {:?}", request);
+    let body = PortPairGroupRoot {
+        port_pair_group: request,
+    };
+    let root: PortPairGroupRoot =
+        session.post_json(NETWORK, &["sfc", "port_pair_groups"], body, None)?;
+    debug!("Created port pair group {:?}", root.port_pair_group);
+    Ok(root.port_pair_group)
+}
+
 /// Create a router.
 pub fn create_router(session: &Session, request: Router) -> Result<Router> {
     debug!("Creating a new router with {:?}", request);
@@ -149,6 +432,24 @@ pub fn create_router(session: &Session, request: Router) -> Result<Router> {
     Ok(root.router)
 }
 
+/// Create an IPsec site connection.
+pub fn create_site_connection(
+    session: &Session,
+    request: SiteConnection,
+) -> Result<SiteConnection> {
+    debug!("Creating a new IPsec site connection with {:?}", request);
+    let body = SiteConnectionRoot {
+        ipsec_site_connection: request,
+    };
+    let root: SiteConnectionRoot =
+        session.post_json(NETWORK, &["vpn", "ipsec-site-connections"], body, None)?;
+    debug!(
+        "Created IPsec site connection {:?}",
+        root.ipsec_site_connection
+    );
+    Ok(root.ipsec_site_connection)
+}
+
 /// Create a subnet.
 pub fn create_subnet(session: &Session, request: Subnet) -> Result<Subnet> {
     debug!("Creating a new subnet with {:?}", request);
@@ -158,6 +459,64 @@ pub fn create_subnet(session: &Session, request: Subnet) -> Result<Subnet> {
     Ok(root.subnet)
 }
 
+/// Create a subnet pool.
+pub fn create_subnet_pool(session: &Session, request: SubnetPool) -> Result<SubnetPool> {
+    debug!("Creating a new subnet pool with {:?}", request);
+    let body = SubnetPoolRoot {
+        subnetpool: request,
+    };
+    let root: SubnetPoolRoot = session.post_json(NETWORK, &["subnetpools"], body, None)?;
+    debug!("Created subnet pool {:?}", root.subnetpool);
+    Ok(root.subnetpool)
+}
+
+/// Create a VPN service.
+pub fn create_vpn_service(session: &Session, request: VpnService) -> Result<VpnService> {
+    debug!("Creating a new VPN service with {:?}", request);
+    let body = VpnServiceRoot {
+        vpnservice: request,
+    };
+    let root: VpnServiceRoot = session.post_json(NETWORK, &["vpn", "vpnservices"], body, None)?;
+    debug!("Created VPN service {:?}", root.vpnservice);
+    Ok(root.vpnservice)
+}
+
+/// Delete an address scope.
+pub fn delete_address_scope<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting address scope {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["address-scopes", id.as_ref()], None)?;
+    debug!("Address scope {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Delete all tags of a resource.
+pub fn delete_all_tags<S: AsRef<str>>(session: &Session, resource_type: &str, id: S) -> Result<()> {
+    trace!("Deleting all tags of {} {}", resource_type, id.as_ref());
+    let _ = session.delete(NETWORK, &[resource_type, id.as_ref(), "tags"], None)?;
+    debug!(
+        "Successfully deleted all tags of {} {}",
+        resource_type,
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Delete a BGP peer.
+pub fn delete_bgp_peer<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting BGP peer {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["bgp-peers", id.as_ref()], None)?;
+    debug!("BGP peer {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Delete a BGP speaker.
+pub fn delete_bgp_speaker<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting BGP speaker {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["bgp-speakers", id.as_ref()], None)?;
+    debug!("BGP speaker {} was deleted", id.as_ref());
+    Ok(())
+}
+
 /// Delete a floating IP.
 pub fn delete_floating_ip<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     debug!("Deleting floating IP {}", id.as_ref());
@@ -166,6 +525,30 @@ pub fn delete_floating_ip<S: AsRef<str>>(session: &Session, id: S) -> Result<()>
     Ok(())
 }
 
+/// Delete a flow classifier.
+pub fn delete_flow_classifier<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting flow classifier {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["sfc", "flow_classifiers", id.as_ref()], None)?;
+    debug!("Flow classifier {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Delete an IKE policy.
+pub fn delete_ike_policy<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting IKE policy {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["vpn", "ikepolicies", id.as_ref()], None)?;
+    debug!("IKE policy {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Delete an IPsec policy.
+pub fn delete_ipsec_policy<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting IPsec policy {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["vpn", "ipsecpolicies", id.as_ref()], None)?;
+    debug!("IPsec policy {} was deleted", id.as_ref());
+    Ok(())
+}
+
 /// Delete a network.
 pub fn delete_network<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     debug!("Deleting network {}", id.as_ref());
@@ -182,6 +565,30 @@ pub fn delete_port<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     Ok(())
 }
 
+/// Delete a port chain.
+pub fn delete_port_chain<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting port chain {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["sfc", "port_chains", id.as_ref()], None)?;
+    debug!("Port chain {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Delete a port pair.
+pub fn delete_port_pair<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting port pair {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["sfc", "port_pairs", id.as_ref()], None)?;
+    debug!("Port pair {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Delete a port pair group.
+pub fn delete_port_pair_group<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting port pair group {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["sfc", "port_pair_groups", id.as_ref()], None)?;
+    debug!("Port pair group {} was deleted", id.as_ref());
+    Ok(())
+}
+
 /// Delete a router.
 pub fn delete_router<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     debug!("Deleting router {}", id.as_ref());
@@ -190,6 +597,18 @@ pub fn delete_router<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     Ok(())
 }
 
+/// Delete an IPsec site connection.
+pub fn delete_site_connection<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting IPsec site connection {}", id.as_ref());
+    let _ = session.delete(
+        NETWORK,
+        &["vpn", "ipsec-site-connections", id.as_ref()],
+        None,
+    )?;
+    debug!("IPsec site connection {} was deleted", id.as_ref());
+    Ok(())
+}
+
 /// Delete a subnet.
 pub fn delete_subnet<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     debug!("Deleting subnet {}", id.as_ref());
@@ -198,104 +617,535 @@ pub fn delete_subnet<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
     Ok(())
 }
 
-/// Get a floating IP.
-pub fn get_floating_ip<S: AsRef<str>>(session: &Session, id: S) -> Result<FloatingIp> {
-    trace!("Get floating IP by ID {}", id.as_ref());
-    let root: FloatingIpRoot = session.get_json(NETWORK, &["floatingips", id.as_ref()], None)?;
-    trace!("Received {:?}", root.floatingip);
-    Ok(root.floatingip)
+/// Delete a subnet pool.
+pub fn delete_subnet_pool<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting subnet pool {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["subnetpools", id.as_ref()], None)?;
+    debug!("Subnet pool {} was deleted", id.as_ref());
+    Ok(())
 }
 
-/// Get a network.
-pub fn get_network<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Network> {
+/// Delete a tag from a resource.
+pub fn delete_tag<S1, S2>(session: &Session, resource_type: &str, id: S1, tag: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Deleting tag {} from {} {}",
+        tag.as_ref(),
+        resource_type,
+        id.as_ref()
+    );
+    let _ = session.delete(
+        NETWORK,
+        &[resource_type, id.as_ref(), "tags", tag.as_ref()],
+        None,
+    )?;
+    debug!(
+        "Successfully deleted tag {} from {} {}",
+        tag.as_ref(),
+        resource_type,
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Delete a VPN service.
+pub fn delete_vpn_service<S: AsRef<str>>(session: &Session, id: S) -> Result<()> {
+    debug!("Deleting VPN service {}", id.as_ref());
+    let _ = session.delete(NETWORK, &["vpn", "vpnservices", id.as_ref()], None)?;
+    debug!("VPN service {} was deleted", id.as_ref());
+    Ok(())
+}
+
+/// Get an address scope.
+pub fn get_address_scope<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<AddressScope> {
     let s = id_or_name.as_ref();
-    get_network_by_id(session, s).if_not_found_then(|| get_network_by_name(session, s))
+    get_address_scope_by_id(session, s).if_not_found_then(|| get_address_scope_by_name(session, s))
 }
 
-/// Get a network by its ID.
-pub fn get_network_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Network> {
-    trace!("Get network by ID {}", id.as_ref());
-    let root: NetworkRoot = session.get_json(NETWORK, &["networks", id.as_ref()], None)?;
-    trace!("Received {:?}", root.network);
-    Ok(root.network)
+/// Get an address scope by its ID.
+pub fn get_address_scope_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<AddressScope> {
+    trace!("Get address scope by ID {}", id.as_ref());
+    let root: AddressScopeRoot =
+        session.get_json(NETWORK, &["address-scopes", id.as_ref()], None)?;
+    trace!("Received {:?}", root.address_scope);
+    Ok(root.address_scope)
 }
 
-/// Get a network by its name.
-pub fn get_network_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Network> {
-    trace!("Get network by name {}", name.as_ref());
-    let root: NetworksRoot =
-        session.get_json_query(NETWORK, &["networks"], &[("name", name.as_ref())], None)?;
+/// Get an address scope by its name.
+pub fn get_address_scope_by_name<S: AsRef<str>>(
+    session: &Session,
+    name: S,
+) -> Result<AddressScope> {
+    trace!("Get address scope by name {}", name.as_ref());
+    let root: AddressScopesRoot = session.get_json_query(
+        NETWORK,
+        &["address-scopes"],
+        &[("name", name.as_ref())],
+        None,
+    )?;
     let result = utils::one(
-        root.networks,
-        "Network with given name or ID not found",
-        "Too many networks found with given name",
+        root.address_scopes,
+        "Address scope with given name or ID not found",
+        "Too many address scopes found with given name",
     )?;
     trace!("Received {:?}", result);
     Ok(result)
 }
 
-/// Get a port.
-pub fn get_port<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Port> {
+/// Get a network agent.
+pub fn get_agent<S: AsRef<str>>(session: &Session, id: S) -> Result<Agent> {
+    trace!("Get network agent {}", id.as_ref());
+    let root: AgentRoot = session.get_json(NETWORK, &["agents", id.as_ref()], None)?;
+    trace!("Received {:?}", root.agent);
+    Ok(root.agent)
+}
+
+/// Get a BGP peer.
+pub fn get_bgp_peer<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<BgpPeer> {
     let s = id_or_name.as_ref();
-    get_port_by_id(session, s).if_not_found_then(|| get_port_by_name(session, s))
+    get_bgp_peer_by_id(session, s).if_not_found_then(|| get_bgp_peer_by_name(session, s))
 }
 
-/// Get a port by its ID.
-pub fn get_port_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Port> {
-    trace!("Get port by ID {}", id.as_ref());
-    let root: PortRoot = session.get_json(NETWORK, &["ports", id.as_ref()], None)?;
-    trace!("Received {:?}", root.port);
-    Ok(root.port)
+/// Get a BGP peer by its ID.
+pub fn get_bgp_peer_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<BgpPeer> {
+    trace!("Get BGP peer by ID {}", id.as_ref());
+    let root: BgpPeerRoot = session.get_json(NETWORK, &["bgp-peers", id.as_ref()], None)?;
+    trace!("Received {:?}", root.bgp_peer);
+    Ok(root.bgp_peer)
 }
 
-/// Get a port by its name.
-pub fn get_port_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Port> {
-    trace!("Get port by name {}", name.as_ref());
-    let root: PortsRoot =
-        session.get_json_query(NETWORK, &["ports"], &[("name", name.as_ref())], None)?;
+/// Get a BGP peer by its name.
+pub fn get_bgp_peer_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<BgpPeer> {
+    trace!("Get BGP peer by name {}", name.as_ref());
+    let root: BgpPeersRoot =
+        session.get_json_query(NETWORK, &["bgp-peers"], &[("name", name.as_ref())], None)?;
     let result = utils::one(
-        root.ports,
-        "Port with given name or ID not found",
-        "Too many ports found with given name",
+        root.bgp_peers,
+        "BGP peer with given name or ID not found",
+        "Too many BGP peers found with given name",
     )?;
     trace!("Received {:?}", result);
     Ok(result)
 }
 
-/// Get a router.
-pub fn get_router<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Router> {
+/// Get a BGP speaker.
+pub fn get_bgp_speaker<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<BgpSpeaker> {
     let s = id_or_name.as_ref();
-    get_router_by_id(session, s).if_not_found_then(|| get_router_by_name(session, s))
+    get_bgp_speaker_by_id(session, s).if_not_found_then(|| get_bgp_speaker_by_name(session, s))
 }
 
-/// Get a router by its ID.
-pub fn get_router_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Router> {
-    trace!("Get router by ID {}", id.as_ref());
-    let root: RouterRoot = session.get_json(NETWORK, &["routers", id.as_ref()], None)?;
-    trace!("Received {:?}", root.router);
-    Ok(root.router)
+/// Get a BGP speaker by its ID.
+pub fn get_bgp_speaker_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<BgpSpeaker> {
+    trace!("Get BGP speaker by ID {}", id.as_ref());
+    let root: BgpSpeakerRoot = session.get_json(NETWORK, &["bgp-speakers", id.as_ref()], None)?;
+    trace!("Received {:?}", root.bgp_speaker);
+    Ok(root.bgp_speaker)
 }
 
-/// Get a router by its name.
-pub fn get_router_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Router> {
-    trace!("Get router by name {}", name.as_ref());
-    let root: RoutersRoot =
-        session.get_json_query(NETWORK, &["routers"], &[("name", name.as_ref())], None)?;
+/// Get a BGP speaker by its name.
+pub fn get_bgp_speaker_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<BgpSpeaker> {
+    trace!("Get BGP speaker by name {}", name.as_ref());
+    let root: BgpSpeakersRoot =
+        session.get_json_query(NETWORK, &["bgp-speakers"], &[("name", name.as_ref())], None)?;
     let result = utils::one(
-        root.routers,
-        "Router with given name or ID not found",
-        "Too many routers found with given name",
+        root.bgp_speakers,
+        "BGP speaker with given name or ID not found",
+        "Too many BGP speakers found with given name",
     )?;
     trace!("Received {:?}", result);
     Ok(result)
 }
 
-/// Get a subnet.
-pub fn get_subnet<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Subnet> {
-    let s = id_or_name.as_ref();
-    get_subnet_by_id(session, s).if_not_found_then(|| get_subnet_by_name(session, s))
-}
-
+/// Get a floating IP.
+pub fn get_floating_ip<S: AsRef<str>>(session: &Session, id: S) -> Result<FloatingIp> {
+    trace!("Get floating IP by ID {}", id.as_ref());
+    let root: FloatingIpRoot = session.get_json(NETWORK, &["floatingips", id.as_ref()], None)?;
+    trace!("Received {:?}", root.floatingip);
+    Ok(root.floatingip)
+}
+
+/// Get a flow classifier.
+pub fn get_flow_classifier<S: AsRef<str>>(
+    session: &Session,
+    id_or_name: S,
+) -> Result<FlowClassifier> {
+    let s = id_or_name.as_ref();
+    get_flow_classifier_by_id(session, s)
+        .if_not_found_then(|| get_flow_classifier_by_name(session, s))
+}
+
+/// Get a flow classifier by its ID.
+pub fn get_flow_classifier_by_id<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+) -> Result<FlowClassifier> {
+    trace!("Get flow classifier by ID {}", id.as_ref());
+    let root: FlowClassifierRoot =
+        session.get_json(NETWORK, &["sfc", "flow_classifiers", id.as_ref()], None)?;
+    trace!("Received {:?}", root.flow_classifier);
+    Ok(root.flow_classifier)
+}
+
+/// Get a flow classifier by its name.
+pub fn get_flow_classifier_by_name<S: AsRef<str>>(
+    session: &Session,
+    name: S,
+) -> Result<FlowClassifier> {
+    trace!("Get flow classifier by name {}", name.as_ref());
+    let root: FlowClassifiersRoot = session.get_json_query(
+        NETWORK,
+        &["sfc", "flow_classifiers"],
+        &[("name", name.as_ref())],
+        None,
+    )?;
+    let result = utils::one(
+        root.flow_classifiers,
+        "Flow classifier with given name or ID not found",
+        "Too many flow classifiers found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get an IKE policy.
+pub fn get_ike_policy<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<IkePolicy> {
+    let s = id_or_name.as_ref();
+    get_ike_policy_by_id(session, s).if_not_found_then(|| get_ike_policy_by_name(session, s))
+}
+
+/// Get an IKE policy by its ID.
+pub fn get_ike_policy_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<IkePolicy> {
+    trace!("Get IKE policy by ID {}", id.as_ref());
+    let root: IkePolicyRoot =
+        session.get_json(NETWORK, &["vpn", "ikepolicies", id.as_ref()], None)?;
+    trace!("Received {:?}", root.ikepolicy);
+    Ok(root.ikepolicy)
+}
+
+/// Get an IKE policy by its name.
+pub fn get_ike_policy_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<IkePolicy> {
+    trace!("Get IKE policy by name {}", name.as_ref());
+    let root: IkePoliciesRoot = session.get_json_query(
+        NETWORK,
+        &["vpn", "ikepolicies"],
+        &[("name", name.as_ref())],
+        None,
+    )?;
+    let result = utils::one(
+        root.ikepolicies,
+        "IKE policy with given name or ID not found",
+        "Too many IKE policies found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get an IPsec policy.
+pub fn get_ipsec_policy<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<IpsecPolicy> {
+    let s = id_or_name.as_ref();
+    get_ipsec_policy_by_id(session, s).if_not_found_then(|| get_ipsec_policy_by_name(session, s))
+}
+
+/// Get an IPsec policy by its ID.
+pub fn get_ipsec_policy_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<IpsecPolicy> {
+    trace!("Get IPsec policy by ID {}", id.as_ref());
+    let root: IpsecPolicyRoot =
+        session.get_json(NETWORK, &["vpn", "ipsecpolicies", id.as_ref()], None)?;
+    trace!("Received {:?}", root.ipsecpolicy);
+    Ok(root.ipsecpolicy)
+}
+
+/// Get an IPsec policy by its name.
+pub fn get_ipsec_policy_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<IpsecPolicy> {
+    trace!("Get IPsec policy by name {}", name.as_ref());
+    let root: IpsecPoliciesRoot = session.get_json_query(
+        NETWORK,
+        &["vpn", "ipsecpolicies"],
+        &[("name", name.as_ref())],
+        None,
+    )?;
+    let result = utils::one(
+        root.ipsecpolicies,
+        "IPsec policy with given name or ID not found",
+        "Too many IPsec policies found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a network.
+pub fn get_network<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Network> {
+    let s = id_or_name.as_ref();
+    get_network_by_id(session, s).if_not_found_then(|| get_network_by_name(session, s))
+}
+
+/// Get a network by its ID.
+pub fn get_network_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Network> {
+    trace!("Get network by ID {}", id.as_ref());
+    let root: NetworkRoot = session.get_json(NETWORK, &["networks", id.as_ref()], None)?;
+    trace!("Received {:?}", root.network);
+    Ok(root.network)
+}
+
+/// Get a network by its name.
+pub fn get_network_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Network> {
+    trace!("Get network by name {}", name.as_ref());
+    let root: NetworksRoot =
+        session.get_json_query(NETWORK, &["networks"], &[("name", name.as_ref())], None)?;
+    let result = utils::one(
+        root.networks,
+        "Network with given name or ID not found",
+        "Too many networks found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a port.
+pub fn get_port<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Port> {
+    let s = id_or_name.as_ref();
+    get_port_by_id(session, s).if_not_found_then(|| get_port_by_name(session, s))
+}
+
+/// Get a port by its ID.
+pub fn get_port_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Port> {
+    trace!("Get port by ID {}", id.as_ref());
+    let root: PortRoot = session.get_json(NETWORK, &["ports", id.as_ref()], None)?;
+    trace!("Received {:?}", root.port);
+    Ok(root.port)
+}
+
+/// Get a port by its name.
+pub fn get_port_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Port> {
+    trace!("Get port by name {}", name.as_ref());
+    let root: PortsRoot =
+        session.get_json_query(NETWORK, &["ports"], &[("name", name.as_ref())], None)?;
+    let result = utils::one(
+        root.ports,
+        "Port with given name or ID not found",
+        "Too many ports found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a port chain.
+pub fn get_port_chain<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<PortChain> {
+    let s = id_or_name.as_ref();
+    get_port_chain_by_id(session, s).if_not_found_then(|| get_port_chain_by_name(session, s))
+}
+
+/// Get a port chain by its ID.
+pub fn get_port_chain_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<PortChain> {
+    trace!("Get port chain by ID {}", id.as_ref());
+    let root: PortChainRoot =
+        session.get_json(NETWORK, &["sfc", "port_chains", id.as_ref()], None)?;
+    trace!("Received {:?}", root.port_chain);
+    Ok(root.port_chain)
+}
+
+/// Get a port chain by its name.
+pub fn get_port_chain_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<PortChain> {
+    trace!("Get port chain by name {}", name.as_ref());
+    let root: PortChainsRoot = session.get_json_query(
+        NETWORK,
+        &["sfc", "port_chains"],
+        &[("name", name.as_ref())],
+        None,
+    )?;
+    let result = utils::one(
+        root.port_chains,
+        "Port chain with given name or ID not found",
+        "Too many port chains found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a port pair.
+pub fn get_port_pair<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<PortPair> {
+    let s = id_or_name.as_ref();
+    get_port_pair_by_id(session, s).if_not_found_then(|| get_port_pair_by_name(session, s))
+}
+
+/// Get a port pair by its ID.
+pub fn get_port_pair_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<PortPair> {
+    trace!("Get port pair by ID {}", id.as_ref());
+    let root: PortPairRoot =
+        session.get_json(NETWORK, &["sfc", "port_pairs", id.as_ref()], None)?;
+    trace!("Received {:?}", root.port_pair);
+    Ok(root.port_pair)
+}
+
+/// Get a port pair by its name.
+pub fn get_port_pair_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<PortPair> {
+    trace!("Get port pair by name {}", name.as_ref());
+    let root: PortPairsRoot = session.get_json_query(
+        NETWORK,
+        &["sfc", "port_pairs"],
+        &[("name", name.as_ref())],
+        None,
+    )?;
+    let result = utils::one(
+        root.port_pairs,
+        "Port pair with given name or ID not found",
+        "Too many port pairs found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a port pair group.
+pub fn get_port_pair_group<S: AsRef<str>>(
+    session: &Session,
+    id_or_name: S,
+) -> Result<PortPairGroup> {
+    let s = id_or_name.as_ref();
+    get_port_pair_group_by_id(session, s)
+        .if_not_found_then(|| get_port_pair_group_by_name(session, s))
+}
+
+/// Get a port pair group by its ID.
+pub fn get_port_pair_group_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<PortPairGroup> {
+    trace!("Get port pair group by ID {}", id.as_ref());
+    let root: PortPairGroupRoot =
+        session.get_json(NETWORK, &["sfc", "port_pair_groups", id.as_ref()], None)?;
+    trace!("Received {:?}", root.port_pair_group);
+    Ok(root.port_pair_group)
+}
+
+/// Get a port pair group by its name.
+pub fn get_port_pair_group_by_name<S: AsRef<str>>(
+    session: &Session,
+    name: S,
+) -> Result<PortPairGroup> {
+    trace!("Get port pair group by name {}", name.as_ref());
+    let root: PortPairGroupsRoot = session.get_json_query(
+        NETWORK,
+        &["sfc", "port_pair_groups"],
+        &[("name", name.as_ref())],
+        None,
+    )?;
+    let result = utils::one(
+        root.port_pair_groups,
+        "Port pair group with given name or ID not found",
+        "Too many port pair groups found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a router.
+pub fn get_router<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Router> {
+    let s = id_or_name.as_ref();
+    get_router_by_id(session, s).if_not_found_then(|| get_router_by_name(session, s))
+}
+
+/// Get a router by its ID.
+pub fn get_router_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Router> {
+    trace!("Get router by ID {}", id.as_ref());
+    let root: RouterRoot = session.get_json(NETWORK, &["routers", id.as_ref()], None)?;
+    trace!("Received {:?}", root.router);
+    Ok(root.router)
+}
+
+/// Get a router by its name.
+pub fn get_router_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Router> {
+    trace!("Get router by name {}", name.as_ref());
+    let root: RoutersRoot =
+        session.get_json_query(NETWORK, &["routers"], &[("name", name.as_ref())], None)?;
+    let result = utils::one(
+        root.routers,
+        "Router with given name or ID not found",
+        "Too many routers found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a network segment.
+pub fn get_segment<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Segment> {
+    let s = id_or_name.as_ref();
+    get_segment_by_id(session, s).if_not_found_then(|| get_segment_by_name(session, s))
+}
+
+/// Get a network segment by its ID.
+pub fn get_segment_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Segment> {
+    trace!("Get network segment by ID {}", id.as_ref());
+    let root: SegmentRoot = session.get_json(NETWORK, &["segments", id.as_ref()], None)?;
+    trace!("Received {:?}", root.segment);
+    Ok(root.segment)
+}
+
+/// Get a network segment by its name.
+pub fn get_segment_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<Segment> {
+    trace!("Get network segment by name {}", name.as_ref());
+    let root: SegmentsRoot =
+        session.get_json_query(NETWORK, &["segments"], &[("name", name.as_ref())], None)?;
+    let result = utils::one(
+        root.segments,
+        "Network segment with given name or ID not found",
+        "Too many network segments found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get an IPsec site connection.
+pub fn get_site_connection<S: AsRef<str>>(
+    session: &Session,
+    id_or_name: S,
+) -> Result<SiteConnection> {
+    let s = id_or_name.as_ref();
+    get_site_connection_by_id(session, s)
+        .if_not_found_then(|| get_site_connection_by_name(session, s))
+}
+
+/// Get an IPsec site connection by its ID.
+pub fn get_site_connection_by_id<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+) -> Result<SiteConnection> {
+    trace!("Get IPsec site connection by ID {}", id.as_ref());
+    let root: SiteConnectionRoot = session.get_json(
+        NETWORK,
+        &["vpn", "ipsec-site-connections", id.as_ref()],
+        None,
+    )?;
+    trace!("Received {:?}", root.ipsec_site_connection);
+    Ok(root.ipsec_site_connection)
+}
+
+/// Get an IPsec site connection by its name.
+pub fn get_site_connection_by_name<S: AsRef<str>>(
+    session: &Session,
+    name: S,
+) -> Result<SiteConnection> {
+    trace!("Get IPsec site connection by name {}", name.as_ref());
+    let root: SiteConnectionsRoot = session.get_json_query(
+        NETWORK,
+        &["vpn", "ipsec-site-connections"],
+        &[("name", name.as_ref())],
+        None,
+    )?;
+    let result = utils::one(
+        root.ipsec_site_connections,
+        "IPsec site connection with given name or ID not found",
+        "Too many IPsec site connections found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a subnet.
+pub fn get_subnet<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<Subnet> {
+    let s = id_or_name.as_ref();
+    get_subnet_by_id(session, s).if_not_found_then(|| get_subnet_by_name(session, s))
+}
+
 /// Get a subnet by its ID.
 pub fn get_subnet_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<Subnet> {
     trace!("Get subnet by ID {}", id.as_ref());
@@ -318,56 +1168,551 @@ pub fn get_subnet_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<S
     Ok(result)
 }
 
+/// Get a subnet pool.
+pub fn get_subnet_pool<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<SubnetPool> {
+    let s = id_or_name.as_ref();
+    get_subnet_pool_by_id(session, s).if_not_found_then(|| get_subnet_pool_by_name(session, s))
+}
+
+/// Get a subnet pool by its ID.
+pub fn get_subnet_pool_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<SubnetPool> {
+    trace!("Get subnet pool by ID {}", id.as_ref());
+    let root: SubnetPoolRoot = session.get_json(NETWORK, &["subnetpools", id.as_ref()], None)?;
+    trace!("Received {:?}", root.subnetpool);
+    Ok(root.subnetpool)
+}
+
+/// Get a subnet pool by its name.
+pub fn get_subnet_pool_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<SubnetPool> {
+    trace!("Get subnet pool by name {}", name.as_ref());
+    let root: SubnetPoolsRoot =
+        session.get_json_query(NETWORK, &["subnetpools"], &[("name", name.as_ref())], None)?;
+    let result = utils::one(
+        root.subnetpools,
+        "Subnet pool with given name or ID not found",
+        "Too many subnet pools found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get a VPN service.
+pub fn get_vpn_service<S: AsRef<str>>(session: &Session, id_or_name: S) -> Result<VpnService> {
+    let s = id_or_name.as_ref();
+    get_vpn_service_by_id(session, s).if_not_found_then(|| get_vpn_service_by_name(session, s))
+}
+
+/// Get a VPN service by its ID.
+pub fn get_vpn_service_by_id<S: AsRef<str>>(session: &Session, id: S) -> Result<VpnService> {
+    trace!("Get VPN service by ID {}", id.as_ref());
+    let root: VpnServiceRoot =
+        session.get_json(NETWORK, &["vpn", "vpnservices", id.as_ref()], None)?;
+    trace!("Received {:?}", root.vpnservice);
+    Ok(root.vpnservice)
+}
+
+/// Get a VPN service by its name.
+pub fn get_vpn_service_by_name<S: AsRef<str>>(session: &Session, name: S) -> Result<VpnService> {
+    trace!("Get VPN service by name {}", name.as_ref());
+    let root: VpnServicesRoot = session.get_json_query(
+        NETWORK,
+        &["vpn", "vpnservices"],
+        &[("name", name.as_ref())],
+        None,
+    )?;
+    let result = utils::one(
+        root.vpnservices,
+        "VPN service with given name or ID not found",
+        "Too many VPN services found with given name",
+    )?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// List address scopes.
+pub fn list_address_scopes<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<AddressScope>, Option<String>)> {
+    trace!("Listing address scopes with {:?}", query);
+    let root: AddressScopesRoot =
+        session.get_json_query(NETWORK, &["address-scopes"], query, None)?;
+    trace!("Received address scopes: {:?}", root.address_scopes);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.address_scopes, next_marker))
+}
+
+/// List BGP speakers hosted by a dynamic routing agent.
+pub fn list_agent_bgp_speakers<S: AsRef<str>>(session: &Session, id: S) -> Result<Vec<BgpSpeaker>> {
+    trace!(
+        "Listing BGP speakers of dynamic routing agent {}",
+        id.as_ref()
+    );
+    let root: BgpSpeakersRoot =
+        session.get_json(NETWORK, &["agents", id.as_ref(), "bgp-drinstances"], None)?;
+    trace!("Received BGP speakers: {:?}", root.bgp_speakers);
+    Ok(root.bgp_speakers)
+}
+
+/// List routers hosted by an L3 agent.
+pub fn list_agent_l3_routers<S: AsRef<str>>(session: &Session, id: S) -> Result<Vec<Router>> {
+    trace!("Listing routers of L3 agent {}", id.as_ref());
+    let root: RoutersRoot =
+        session.get_json(NETWORK, &["agents", id.as_ref(), "l3-routers"], None)?;
+    trace!("Received routers: {:?}", root.routers);
+    Ok(root.routers)
+}
+
+/// List network agents.
+pub fn list_agents<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<Agent>, Option<String>)> {
+    trace!("Listing network agents with {:?}", query);
+    let root: AgentsRoot = session.get_json_query(NETWORK, &["agents"], query, None)?;
+    trace!("Received network agents: {:?}", root.agents);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.agents, next_marker))
+}
+
+/// List BGP peers.
+pub fn list_bgp_peers<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<BgpPeer>, Option<String>)> {
+    trace!("Listing BGP peers with {:?}", query);
+    let root: BgpPeersRoot = session.get_json_query(NETWORK, &["bgp-peers"], query, None)?;
+    trace!("Received BGP peers: {:?}", root.bgp_peers);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.bgp_peers, next_marker))
+}
+
+/// List routes advertised by a BGP speaker.
+pub fn list_bgp_speaker_advertised_routes<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+) -> Result<Vec<BgpRoute>> {
+    trace!("Listing routes advertised by BGP speaker {}", id.as_ref());
+    let root: BgpRoutesRoot = session.get_json(
+        NETWORK,
+        &["bgp-speakers", id.as_ref(), "get_advertised_routes"],
+        None,
+    )?;
+    trace!("Received advertised routes: {:?}", root.advertised_routes);
+    Ok(root.advertised_routes)
+}
+
+/// List dynamic routing agents hosting a BGP speaker.
+pub fn list_bgp_speaker_dr_agents<S: AsRef<str>>(session: &Session, id: S) -> Result<Vec<Agent>> {
+    trace!(
+        "Listing dynamic routing agents of BGP speaker {}",
+        id.as_ref()
+    );
+    let root: AgentsRoot = session.get_json(
+        NETWORK,
+        &["bgp-speakers", id.as_ref(), "bgp-dragents"],
+        None,
+    )?;
+    trace!("Received dynamic routing agents: {:?}", root.agents);
+    Ok(root.agents)
+}
+
+/// List BGP speakers.
+pub fn list_bgp_speakers<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<BgpSpeaker>, Option<String>)> {
+    trace!("Listing BGP speakers with {:?}", query);
+    let root: BgpSpeakersRoot = session.get_json_query(NETWORK, &["bgp-speakers"], query, None)?;
+    trace!("Received BGP speakers: {:?}", root.bgp_speakers);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.bgp_speakers, next_marker))
+}
+
 /// List floating IPs.
 pub fn list_floating_ips<Q: Serialize + Sync + Debug>(
     session: &Session,
     query: &Q,
-) -> Result<Vec<FloatingIp>> {
+) -> Result<(Vec<FloatingIp>, Option<String>)> {
     trace!("Listing floating IPs with {:?}", query);
     let root: FloatingIpsRoot = session.get_json_query(NETWORK, &["floatingips"], query, None)?;
     trace!("Received floating IPs: {:?}", root.floatingips);
-    Ok(root.floatingips)
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.floatingips, next_marker))
+}
+
+/// List flow classifiers.
+pub fn list_flow_classifiers<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<FlowClassifier>, Option<String>)> {
+    trace!("Listing flow classifiers with {:?}", query);
+    let root: FlowClassifiersRoot =
+        session.get_json_query(NETWORK, &["sfc", "flow_classifiers"], query, None)?;
+    trace!("Received flow classifiers: {:?}", root.flow_classifiers);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.flow_classifiers, next_marker))
+}
+
+/// List IKE policies.
+pub fn list_ike_policies<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<IkePolicy>, Option<String>)> {
+    trace!("Listing IKE policies with {:?}", query);
+    let root: IkePoliciesRoot =
+        session.get_json_query(NETWORK, &["vpn", "ikepolicies"], query, None)?;
+    trace!("Received IKE policies: {:?}", root.ikepolicies);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.ikepolicies, next_marker))
+}
+
+/// List IPsec policies.
+pub fn list_ipsec_policies<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<IpsecPolicy>, Option<String>)> {
+    trace!("Listing IPsec policies with {:?}", query);
+    let root: IpsecPoliciesRoot =
+        session.get_json_query(NETWORK, &["vpn", "ipsecpolicies"], query, None)?;
+    trace!("Received IPsec policies: {:?}", root.ipsecpolicies);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.ipsecpolicies, next_marker))
+}
+
+/// List DHCP agents hosting a network.
+pub fn list_network_dhcp_agents<S: AsRef<str>>(session: &Session, id: S) -> Result<Vec<Agent>> {
+    trace!("Listing DHCP agents of network {}", id.as_ref());
+    let root: AgentsRoot =
+        session.get_json(NETWORK, &["networks", id.as_ref(), "dhcp-agents"], None)?;
+    trace!("Received DHCP agents: {:?}", root.agents);
+    Ok(root.agents)
 }
 
 /// List networks.
 pub fn list_networks<Q: Serialize + Sync + Debug>(
     session: &Session,
     query: &Q,
-) -> Result<Vec<Network>> {
+) -> Result<(Vec<Network>, Option<String>)> {
     trace!("Listing networks with {:?}", query);
     let root: NetworksRoot = session.get_json_query(NETWORK, &["networks"], query, None)?;
     trace!("Received networks: {:?}", root.networks);
-    Ok(root.networks)
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.networks, next_marker))
+}
+
+/// List port chains.
+pub fn list_port_chains<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<PortChain>, Option<String>)> {
+    trace!("Listing port chains with {:?}", query);
+    let root: PortChainsRoot =
+        session.get_json_query(NETWORK, &["sfc", "port_chains"], query, None)?;
+    trace!("Received port chains: {:?}", root.port_chains);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.port_chains, next_marker))
+}
+
+/// List port pair groups.
+pub fn list_port_pair_groups<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<PortPairGroup>, Option<String>)> {
+    trace!("Listing port pair groups with {:?}", query);
+    let root: PortPairGroupsRoot =
+        session.get_json_query(NETWORK, &["sfc", "port_pair_groups"], query, None)?;
+    trace!("Received port pair groups: {:?}", root.port_pair_groups);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.port_pair_groups, next_marker))
+}
+
+/// List port pairs.
+pub fn list_port_pairs<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<PortPair>, Option<String>)> {
+    trace!("Listing port pairs with {:?}", query);
+    let root: PortPairsRoot =
+        session.get_json_query(NETWORK, &["sfc", "port_pairs"], query, None)?;
+    trace!("Received port pairs: {:?}", root.port_pairs);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.port_pairs, next_marker))
 }
 
 /// List ports.
-pub fn list_ports<Q: Serialize + Sync + Debug>(session: &Session, query: &Q) -> Result<Vec<Port>> {
+pub fn list_ports<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<Port>, Option<String>)> {
     trace!("Listing ports with {:?}", query);
     let root: PortsRoot = session.get_json_query(NETWORK, &["ports"], query, None)?;
     trace!("Received ports: {:?}", root.ports);
-    Ok(root.ports)
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.ports, next_marker))
+}
+
+/// List L3 agents hosting a router.
+pub fn list_router_l3_agents<S: AsRef<str>>(session: &Session, id: S) -> Result<Vec<Agent>> {
+    trace!("Listing L3 agents of router {}", id.as_ref());
+    let root: AgentsRoot =
+        session.get_json(NETWORK, &["routers", id.as_ref(), "l3-agents"], None)?;
+    trace!("Received L3 agents: {:?}", root.agents);
+    Ok(root.agents)
 }
 
 /// List routers.
 pub fn list_routers<Q: Serialize + Sync + Debug>(
     session: &Session,
     query: &Q,
-) -> Result<Vec<Router>> {
+) -> Result<(Vec<Router>, Option<String>)> {
     trace!("Listing routers with {:?}", query);
     let root: RoutersRoot = session.get_json_query(NETWORK, &["routers"], query, None)?;
     trace!("Received routers: {:?}", root.routers);
-    Ok(root.routers)
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.routers, next_marker))
+}
+
+/// List network segments.
+pub fn list_segments<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<Segment>, Option<String>)> {
+    trace!("Listing network segments with {:?}", query);
+    let root: SegmentsRoot = session.get_json_query(NETWORK, &["segments"], query, None)?;
+    trace!("Received network segments: {:?}", root.segments);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.segments, next_marker))
+}
+
+/// List IPsec site connections.
+pub fn list_site_connections<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<SiteConnection>, Option<String>)> {
+    trace!("Listing IPsec site connections with {:?}", query);
+    let root: SiteConnectionsRoot =
+        session.get_json_query(NETWORK, &["vpn", "ipsec-site-connections"], query, None)?;
+    trace!(
+        "Received IPsec site connections: {:?}",
+        root.ipsec_site_connections
+    );
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.ipsec_site_connections, next_marker))
 }
 
 /// List subnets.
 pub fn list_subnets<Q: Serialize + Sync + Debug>(
     session: &Session,
     query: &Q,
-) -> Result<Vec<Subnet>> {
+) -> Result<(Vec<Subnet>, Option<String>)> {
     trace!("Listing subnets with {:?}", query);
     let root: SubnetsRoot = session.get_json_query(NETWORK, &["subnets"], query, None)?;
     trace!("Received subnets: {:?}", root.subnets);
-    Ok(root.subnets)
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.subnets, next_marker))
+}
+
+/// List subnet pools.
+pub fn list_subnet_pools<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<SubnetPool>, Option<String>)> {
+    trace!("Listing subnet pools with {:?}", query);
+    let root: SubnetPoolsRoot = session.get_json_query(NETWORK, &["subnetpools"], query, None)?;
+    trace!("Received subnet pools: {:?}", root.subnetpools);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.subnetpools, next_marker))
+}
+
+/// List tags of a resource.
+pub fn list_tags<S: AsRef<str>>(
+    session: &Session,
+    resource_type: &str,
+    id: S,
+) -> Result<Vec<String>> {
+    trace!("Listing tags of {} {}", resource_type, id.as_ref());
+    let root: Tags = session.get_json(NETWORK, &[resource_type, id.as_ref(), "tags"], None)?;
+    trace!("Received tags: {:?}", root.tags);
+    Ok(root.tags)
+}
+
+/// List VPN services.
+pub fn list_vpn_services<Q: Serialize + Sync + Debug>(
+    session: &Session,
+    query: &Q,
+) -> Result<(Vec<VpnService>, Option<String>)> {
+    trace!("Listing VPN services with {:?}", query);
+    let root: VpnServicesRoot =
+        session.get_json_query(NETWORK, &["vpn", "vpnservices"], query, None)?;
+    trace!("Received VPN services: {:?}", root.vpnservices);
+    let next_marker = protocol::next_marker(&root.links);
+    Ok((root.vpnservices, next_marker))
+}
+
+/// Remove a BGP speaker from a dynamic routing agent.
+pub fn remove_bgp_speaker_from_dr_agent<S1, S2>(
+    session: &Session,
+    agent_id: S1,
+    speaker_id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Remove BGP speaker {} from dynamic routing agent {}",
+        speaker_id.as_ref(),
+        agent_id.as_ref()
+    );
+    let _ = session.delete(
+        NETWORK,
+        &[
+            "agents",
+            agent_id.as_ref(),
+            "bgp-drinstances",
+            speaker_id.as_ref(),
+        ],
+        None,
+    )?;
+    debug!(
+        "Successfully removed BGP speaker {} from dynamic routing agent {}",
+        speaker_id.as_ref(),
+        agent_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Remove a gateway network from a BGP speaker.
+pub fn remove_bgp_speaker_network<S1, S2>(
+    session: &Session,
+    speaker_id: S1,
+    network_id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Remove gateway network {} from BGP speaker {}",
+        network_id.as_ref(),
+        speaker_id.as_ref()
+    );
+    let mut body = HashMap::new();
+    let _ = body.insert("network_id", network_id.as_ref());
+
+    let _ = session.put(
+        NETWORK,
+        &[
+            "bgp-speakers",
+            speaker_id.as_ref(),
+            "remove_gateway_network",
+        ],
+        body,
+        None,
+    )?;
+
+    debug!(
+        "Successfully removed gateway network {} from BGP speaker {}",
+        network_id.as_ref(),
+        speaker_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Remove a peer from a BGP speaker.
+pub fn remove_bgp_speaker_peer<S1, S2>(session: &Session, speaker_id: S1, peer_id: S2) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Remove peer {} from BGP speaker {}",
+        peer_id.as_ref(),
+        speaker_id.as_ref()
+    );
+    let mut body = HashMap::new();
+    let _ = body.insert("bgp_peer_id", peer_id.as_ref());
+
+    let _ = session.put(
+        NETWORK,
+        &["bgp-speakers", speaker_id.as_ref(), "remove_bgp_peer"],
+        body,
+        None,
+    )?;
+
+    debug!(
+        "Successfully removed peer {} from BGP speaker {}",
+        peer_id.as_ref(),
+        speaker_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Remove a network from a DHCP agent.
+pub fn remove_network_from_dhcp_agent<S1, S2>(
+    session: &Session,
+    agent_id: S1,
+    network_id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Remove network {} from DHCP agent {}",
+        network_id.as_ref(),
+        agent_id.as_ref()
+    );
+    let _ = session.delete(
+        NETWORK,
+        &[
+            "agents",
+            agent_id.as_ref(),
+            "dhcp-networks",
+            network_id.as_ref(),
+        ],
+        None,
+    )?;
+    debug!(
+        "Successfully removed network {} from DHCP agent {}",
+        network_id.as_ref(),
+        agent_id.as_ref()
+    );
+    Ok(())
+}
+
+/// Remove a router from an L3 agent.
+pub fn remove_router_from_l3_agent<S1, S2>(
+    session: &Session,
+    agent_id: S1,
+    router_id: S2,
+) -> Result<()>
+where
+    S1: AsRef<str>,
+    S2: AsRef<str>,
+{
+    trace!(
+        "Remove router {} from L3 agent {}",
+        router_id.as_ref(),
+        agent_id.as_ref()
+    );
+    let _ = session.delete(
+        NETWORK,
+        &[
+            "agents",
+            agent_id.as_ref(),
+            "l3-routers",
+            router_id.as_ref(),
+        ],
+        None,
+    )?;
+    debug!(
+        "Successfully removed router {} from L3 agent {}",
+        router_id.as_ref(),
+        agent_id.as_ref()
+    );
+    Ok(())
 }
 
 /// Remove an interface from a router.
@@ -418,6 +1763,78 @@ where
     Ok(())
 }
 
+/// Replace all tags of a resource.
+pub fn replace_tags<S: AsRef<str>>(
+    session: &Session,
+    resource_type: &str,
+    id: S,
+    tags: Vec<String>,
+) -> Result<()> {
+    trace!(
+        "Replacing tags of {} {} with {:?}",
+        resource_type,
+        id.as_ref(),
+        tags
+    );
+    let _ = session.put(
+        NETWORK,
+        &[resource_type, id.as_ref(), "tags"],
+        Tags { tags },
+        None,
+    )?;
+    debug!(
+        "Successfully replaced tags of {} {}",
+        resource_type,
+        id.as_ref()
+    );
+    Ok(())
+}
+
+/// Update an address scope.
+pub fn update_address_scope<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: AddressScopeUpdate,
+) -> Result<AddressScope> {
+    debug!("Updating address scope {} with {:?}", id.as_ref(), update);
+    let body = AddressScopeUpdateRoot {
+        address_scope: update,
+    };
+    let root: AddressScopeRoot =
+        session.put_json(NETWORK, &["address-scopes", id.as_ref()], body, None)?;
+    debug!("Updated address scope {:?}", root.address_scope);
+    Ok(root.address_scope)
+}
+
+/// Update a BGP peer.
+pub fn update_bgp_peer<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: BgpPeerUpdate,
+) -> Result<BgpPeer> {
+    debug!("Updating BGP peer {} with {:?}", id.as_ref(), update);
+    let body = BgpPeerUpdateRoot { bgp_peer: update };
+    let root: BgpPeerRoot = session.put_json(NETWORK, &["bgp-peers", id.as_ref()], body, None)?;
+    debug!("Updated BGP peer {:?}", root.bgp_peer);
+    Ok(root.bgp_peer)
+}
+
+/// Update a BGP speaker.
+pub fn update_bgp_speaker<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: BgpSpeakerUpdate,
+) -> Result<BgpSpeaker> {
+    debug!("Updating BGP speaker {} with {:?}", id.as_ref(), update);
+    let body = BgpSpeakerUpdateRoot {
+        bgp_speaker: update,
+    };
+    let root: BgpSpeakerRoot =
+        session.put_json(NETWORK, &["bgp-speakers", id.as_ref()], body, None)?;
+    debug!("Updated BGP speaker {:?}", root.bgp_speaker);
+    Ok(root.bgp_speaker)
+}
+
 /// Update a floating IP.
 pub fn update_floating_ip<S: AsRef<str>>(
     session: &Session,
@@ -432,6 +1849,56 @@ pub fn update_floating_ip<S: AsRef<str>>(
     Ok(root.floatingip)
 }
 
+/// Update a flow classifier.
+pub fn update_flow_classifier<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: FlowClassifierUpdate,
+) -> Result<FlowClassifier> {
+    debug!("Updating flow classifier {} with {:?}", id.as_ref(), update);
+    let body = FlowClassifierUpdateRoot {
+        flow_classifier: update,
+    };
+    let root: FlowClassifierRoot = session.put_json(
+        NETWORK,
+        &["sfc", "flow_classifiers", id.as_ref()],
+        body,
+        None,
+    )?;
+    debug!("Updated flow classifier {:?}", root.flow_classifier);
+    Ok(root.flow_classifier)
+}
+
+/// Update an IKE policy.
+pub fn update_ike_policy<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: IkePolicyUpdate,
+) -> Result<IkePolicy> {
+    debug!("Updating IKE policy {} with {:?}", id.as_ref(), update);
+    let body = IkePolicyUpdateRoot { ikepolicy: update };
+    let root: IkePolicyRoot =
+        session.put_json(NETWORK, &["vpn", "ikepolicies", id.as_ref()], body, None)?;
+    debug!("Updated IKE policy {:?}", root.ikepolicy);
+    Ok(root.ikepolicy)
+}
+
+/// Update an IPsec policy.
+pub fn update_ipsec_policy<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: IpsecPolicyUpdate,
+) -> Result<IpsecPolicy> {
+    debug!("Updating IPsec policy {} with {:?}", id.as_ref(), update);
+    let body = IpsecPolicyUpdateRoot {
+        ipsecpolicy: update,
+    };
+    let root: IpsecPolicyRoot =
+        session.put_json(NETWORK, &["vpn", "ipsecpolicies", id.as_ref()], body, None)?;
+    debug!("Updated IPsec policy {:?}", root.ipsecpolicy);
+    Ok(root.ipsecpolicy)
+}
+
 /// Update a network.
 pub fn update_network<S: AsRef<str>>(
     session: &Session,
@@ -454,6 +1921,54 @@ pub fn update_port<S: AsRef<str>>(session: &Session, id: S, update: PortUpdate)
     Ok(root.port)
 }
 
+/// Update a port chain.
+pub fn update_port_chain<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: PortChainUpdate,
+) -> Result<PortChain> {
+    debug!("Updating port chain {} with {:?}", id.as_ref(), update);
+    let body = PortChainUpdateRoot { port_chain: update };
+    let root: PortChainRoot =
+        session.put_json(NETWORK, &["sfc", "port_chains", id.as_ref()], body, None)?;
+    debug!("Updated port chain {:?}", root.port_chain);
+    Ok(root.port_chain)
+}
+
+/// Update a port pair.
+pub fn update_port_pair<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: PortPairUpdate,
+) -> Result<PortPair> {
+    debug!("Updating port pair {} with {:?}", id.as_ref(), update);
+    let body = PortPairUpdateRoot { port_pair: update };
+    let root: PortPairRoot =
+        session.put_json(NETWORK, &["sfc", "port_pairs", id.as_ref()], body, None)?;
+    debug!("Updated port pair {:?}", root.port_pair);
+    Ok(root.port_pair)
+}
+
+/// Update a port pair group.
+pub fn update_port_pair_group<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: PortPairGroupUpdate,
+) -> Result<PortPairGroup> {
+    debug!("Updating port pair group {} with {:?}", id.as_ref(), update);
+    let body = PortPairGroupUpdateRoot {
+        port_pair_group: update,
+    };
+    let root: PortPairGroupRoot = session.put_json(
+        NETWORK,
+        &["sfc", "port_pair_groups", id.as_ref()],
+        body,
+        None,
+    )?;
+    debug!("Updated port pair group {:?}", root.port_pair_group);
+    Ok(root.port_pair_group)
+}
+
 /// Update a router.
 pub fn update_router<S: AsRef<str>>(
     session: &Session,
@@ -467,6 +1982,33 @@ pub fn update_router<S: AsRef<str>>(
     Ok(root.router)
 }
 
+/// Update an IPsec site connection.
+pub fn update_site_connection<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: SiteConnectionUpdate,
+) -> Result<SiteConnection> {
+    debug!(
+        "Updating IPsec site connection {} with {:?}",
+        id.as_ref(),
+        update
+    );
+    let body = SiteConnectionUpdateRoot {
+        ipsec_site_connection: update,
+    };
+    let root: SiteConnectionRoot = session.put_json(
+        NETWORK,
+        &["vpn", "ipsec-site-connections", id.as_ref()],
+        body,
+        None,
+    )?;
+    debug!(
+        "Updated IPsec site connection {:?}",
+        root.ipsec_site_connection
+    );
+    Ok(root.ipsec_site_connection)
+}
+
 /// Update a subnet.
 pub fn update_subnet<S: AsRef<str>>(
     session: &Session,
@@ -479,3 +2021,31 @@ pub fn update_subnet<S: AsRef<str>>(
     debug!("Updated subnet {:?}", root.subnet);
     Ok(root.subnet)
 }
+
+/// Update a subnet pool.
+pub fn update_subnet_pool<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: SubnetPoolUpdate,
+) -> Result<SubnetPool> {
+    debug!("Updating subnet pool {} with {:?}", id.as_ref(), update);
+    let body = SubnetPoolUpdateRoot { subnetpool: update };
+    let root: SubnetPoolRoot =
+        session.put_json(NETWORK, &["subnetpools", id.as_ref()], body, None)?;
+    debug!("Updated subnet pool {:?}", root.subnetpool);
+    Ok(root.subnetpool)
+}
+
+/// Update a VPN service.
+pub fn update_vpn_service<S: AsRef<str>>(
+    session: &Session,
+    id: S,
+    update: VpnServiceUpdate,
+) -> Result<VpnService> {
+    debug!("Updating VPN service {} with {:?}", id.as_ref(), update);
+    let body = VpnServiceUpdateRoot { vpnservice: update };
+    let root: VpnServiceRoot =
+        session.put_json(NETWORK, &["vpn", "vpnservices", id.as_ref()], body, None)?;
+    debug!("Updated VPN service {:?}", root.vpnservice);
+    Ok(root.vpnservice)
+}
@@ -0,0 +1,330 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! BGP peer management via Network API (BGP dynamic routing).
+
+use std::collections::HashSet;
+use std::net;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    BgpPeerRef, DeletionWaiter, IntoVerified, Refresh, ResourceIterator, ResourceQuery,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol};
+
+/// A query to BGP peer list.
+#[derive(Clone, Debug)]
+pub struct BgpPeerQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single BGP peer.
+#[derive(Clone, Debug)]
+pub struct BgpPeer {
+    session: Rc<Session>,
+    inner: protocol::BgpPeer,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a BGP peer.
+#[derive(Clone, Debug)]
+pub struct NewBgpPeer {
+    session: Rc<Session>,
+    inner: protocol::BgpPeer,
+}
+
+impl BgpPeer {
+    /// Create a BGP peer object.
+    fn new(session: Rc<Session>, inner: protocol::BgpPeer) -> BgpPeer {
+        BgpPeer {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a BgpPeer object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<BgpPeer> {
+        let inner = api::get_bgp_peer(&session, id)?;
+        Ok(BgpPeer::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Authentication type of the BGP peer."]
+        auth_type: protocol::BgpAuthType
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "BGP peer name."]
+        name: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
+    update_field! {
+        #[doc = "Update the authentication password."]
+        set_password, with_password -> password: optional String
+    }
+
+    transparent_property! {
+        #[doc = "IP address of the BGP peer."]
+        peer_ip: net::IpAddr
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Autonomous system number of the BGP peer."]
+        remote_as: u32
+    }
+
+    /// Delete the BGP peer.
+    pub fn delete(self) -> Result<DeletionWaiter<BgpPeer>> {
+        api::delete_bgp_peer(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the BGP peer is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the BGP peer.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::BgpPeerUpdate::default();
+        save_fields! {
+            self -> update: name
+        };
+        save_option_fields! {
+            self -> update: password
+        };
+        let inner = api::update_bgp_peer(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for BgpPeer {
+    /// Refresh the BGP peer.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_bgp_peer_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl BgpPeerQuery {
+    pub(crate) fn new(session: Rc<Session>) -> BgpPeerQuery {
+        BgpPeerQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by BGP peer name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<BgpPeerQuery> {
+        debug!("Fetching BGP peers with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<BgpPeer>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<BgpPeer> {
+        debug!("Fetching one BGP peer with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for BgpPeerQuery {
+    type Item = BgpPeer;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_bgp_peers(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| BgpPeer::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl NewBgpPeer {
+    /// Start creating a BGP peer.
+    pub(crate) fn new(
+        session: Rc<Session>,
+        name: String,
+        peer_ip: net::IpAddr,
+        remote_as: u32,
+    ) -> NewBgpPeer {
+        NewBgpPeer {
+            session,
+            inner: protocol::BgpPeer {
+                auth_type: protocol::BgpAuthType::None,
+                id: String::new(),
+                name,
+                password: None,
+                peer_ip,
+                project_id: None,
+                remote_as,
+            },
+        }
+    }
+
+    /// Request creation of the BGP peer.
+    pub fn create(self) -> Result<BgpPeer> {
+        let inner = api::create_bgp_peer(&self.session, self.inner)?;
+        Ok(BgpPeer::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the authentication type of the BGP peer."]
+        set_auth_type, with_auth_type -> auth_type: protocol::BgpAuthType
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the authentication password of the BGP peer."]
+        set_password, with_password -> password: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the BGP peer."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+}
+
+impl IntoFallibleIterator for BgpPeerQuery {
+    type Item = BgpPeer;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<BgpPeerQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<BgpPeer> for BgpPeerRef {
+    fn from(value: BgpPeer) -> BgpPeerRef {
+        BgpPeerRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a BgpPeer> for BgpPeerRef {
+    fn from(value: &'a BgpPeer) -> BgpPeerRef {
+        BgpPeerRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for BgpPeerRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<BgpPeerRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            BgpPeerRef::new_verified(api::get_bgp_peer(session, &self.value)?.id)
+        })
+    }
+}
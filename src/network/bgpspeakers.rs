@@ -0,0 +1,419 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! BGP speaker management via Network API (BGP dynamic routing).
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    BgpPeerRef, BgpSpeakerRef, DeletionWaiter, IntoVerified, NetworkRef, Refresh, ResourceIterator,
+    ResourceQuery,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol, BgpPeer, NetworkAgent};
+
+/// A query to BGP speaker list.
+#[derive(Clone, Debug)]
+pub struct BgpSpeakerQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single BGP speaker.
+#[derive(Clone, Debug)]
+pub struct BgpSpeaker {
+    session: Rc<Session>,
+    inner: protocol::BgpSpeaker,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a BGP speaker.
+#[derive(Clone, Debug)]
+pub struct NewBgpSpeaker {
+    session: Rc<Session>,
+    inner: protocol::BgpSpeaker,
+}
+
+impl BgpSpeaker {
+    /// Create a BGP speaker object.
+    pub(crate) fn new(session: Rc<Session>, inner: protocol::BgpSpeaker) -> BgpSpeaker {
+        BgpSpeaker {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a BgpSpeaker object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<BgpSpeaker> {
+        let inner = api::get_bgp_speaker(&session, id)?;
+        Ok(BgpSpeaker::new(session, inner))
+    }
+
+    /// Schedule the BGP speaker to a dynamic routing agent (requires administrator rights).
+    pub fn add_dr_agent<S: AsRef<str>>(&self, agent_id: S) -> Result<()> {
+        api::add_bgp_speaker_to_dr_agent(&self.session, agent_id, &self.inner.id)
+    }
+
+    /// Add a gateway network to the BGP speaker.
+    pub fn add_network<N: Into<NetworkRef>>(&mut self, network: N) -> Result<()> {
+        let network = network.into().into_verified(&self.session)?;
+        api::add_bgp_speaker_network(&self.session, &self.inner.id, &network)?;
+        self.inner.networks.push(network.into());
+        Ok(())
+    }
+
+    /// Add a BGP peer to the BGP speaker.
+    pub fn add_peer<P: Into<BgpPeerRef>>(&mut self, peer: P) -> Result<()> {
+        let peer = peer.into().into_verified(&self.session)?;
+        api::add_bgp_speaker_peer(&self.session, &self.inner.id, &peer)?;
+        self.inner.peers.push(peer.into());
+        Ok(())
+    }
+
+    transparent_property! {
+        #[doc = "Whether host routes for floating IPs are advertised."]
+        advertise_floating_ip_host_routes: bool
+    }
+
+    update_field! {
+        #[doc = "Set whether host routes for floating IPs are advertised."]
+        set_advertise_floating_ip_host_routes, with_advertise_floating_ip_host_routes
+            -> advertise_floating_ip_host_routes: bool
+    }
+
+    transparent_property! {
+        #[doc = "Whether tenant networks are advertised."]
+        advertise_tenant_networks: bool
+    }
+
+    update_field! {
+        #[doc = "Set whether tenant networks are advertised."]
+        set_advertise_tenant_networks, with_advertise_tenant_networks
+            -> advertise_tenant_networks: bool
+    }
+
+    /// Fetch the routes advertised by the BGP speaker.
+    pub fn advertised_routes(&self) -> Result<Vec<protocol::BgpRoute>> {
+        api::list_bgp_speaker_advertised_routes(&self.session, &self.inner.id)
+    }
+
+    /// List dynamic routing agents hosting the BGP speaker (requires administrator rights).
+    pub fn dr_agents(&self) -> Result<Vec<NetworkAgent>> {
+        Ok(
+            api::list_bgp_speaker_dr_agents(&self.session, &self.inner.id)?
+                .into_iter()
+                .map(|item| NetworkAgent::new(self.session.clone(), item))
+                .collect(),
+        )
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "IP protocol version of the BGP speaker."]
+        ip_version: protocol::IpVersion
+    }
+
+    transparent_property! {
+        #[doc = "Local autonomous system number."]
+        local_as: u32
+    }
+
+    transparent_property! {
+        #[doc = "BGP speaker name."]
+        name: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name
+    }
+
+    /// IDs of the gateway networks of the BGP speaker.
+    pub fn network_ids(&self) -> &Vec<String> {
+        &self.inner.networks
+    }
+
+    /// IDs of the BGP peers of the BGP speaker.
+    pub fn peer_ids(&self) -> &Vec<String> {
+        &self.inner.peers
+    }
+
+    /// Fetch the BGP peers of the BGP speaker.
+    pub fn peers(&self) -> Result<Vec<BgpPeer>> {
+        self.inner
+            .peers
+            .iter()
+            .map(|id| BgpPeer::load(self.session.clone(), id))
+            .collect()
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    /// Remove the BGP speaker from a dynamic routing agent (requires administrator rights).
+    pub fn remove_dr_agent<S: AsRef<str>>(&self, agent_id: S) -> Result<()> {
+        api::remove_bgp_speaker_from_dr_agent(&self.session, agent_id, &self.inner.id)
+    }
+
+    /// Remove a gateway network from the BGP speaker.
+    pub fn remove_network<N: Into<NetworkRef>>(&mut self, network: N) -> Result<()> {
+        let network = network.into().into_verified(&self.session)?;
+        api::remove_bgp_speaker_network(&self.session, &self.inner.id, &network)?;
+        self.inner.networks.retain(|id| id != network.as_ref());
+        Ok(())
+    }
+
+    /// Remove a BGP peer from the BGP speaker.
+    pub fn remove_peer<P: Into<BgpPeerRef>>(&mut self, peer: P) -> Result<()> {
+        let peer = peer.into().into_verified(&self.session)?;
+        api::remove_bgp_speaker_peer(&self.session, &self.inner.id, &peer)?;
+        self.inner.peers.retain(|id| id != peer.as_ref());
+        Ok(())
+    }
+
+    /// Delete the BGP speaker.
+    pub fn delete(self) -> Result<DeletionWaiter<BgpSpeaker>> {
+        api::delete_bgp_speaker(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the BGP speaker is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the BGP speaker.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::BgpSpeakerUpdate::default();
+        save_fields! {
+            self -> update: advertise_floating_ip_host_routes advertise_tenant_networks name
+        };
+        let inner = api::update_bgp_speaker(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for BgpSpeaker {
+    /// Refresh the BGP speaker.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_bgp_speaker_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl BgpSpeakerQuery {
+    pub(crate) fn new(session: Rc<Session>) -> BgpSpeakerQuery {
+        BgpSpeakerQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by BGP speaker name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<BgpSpeakerQuery> {
+        debug!("Fetching BGP speakers with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<BgpSpeaker>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<BgpSpeaker> {
+        debug!("Fetching one BGP speaker with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for BgpSpeakerQuery {
+    type Item = BgpSpeaker;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_bgp_speakers(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| BgpSpeaker::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl NewBgpSpeaker {
+    /// Start creating a BGP speaker.
+    pub(crate) fn new(session: Rc<Session>, name: String, local_as: u32) -> NewBgpSpeaker {
+        NewBgpSpeaker {
+            session,
+            inner: protocol::BgpSpeaker {
+                advertise_floating_ip_host_routes: true,
+                advertise_tenant_networks: true,
+                id: String::new(),
+                ip_version: protocol::IpVersion::V4,
+                local_as,
+                name,
+                networks: Vec::new(),
+                peers: Vec::new(),
+                project_id: None,
+            },
+        }
+    }
+
+    /// Request creation of the BGP speaker.
+    pub fn create(self) -> Result<BgpSpeaker> {
+        let inner = api::create_bgp_speaker(&self.session, self.inner)?;
+        Ok(BgpSpeaker::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether host routes for floating IPs are advertised."]
+        set_advertise_floating_ip_host_routes, with_advertise_floating_ip_host_routes
+            -> advertise_floating_ip_host_routes: bool
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether tenant networks are advertised."]
+        set_advertise_tenant_networks, with_advertise_tenant_networks
+            -> advertise_tenant_networks: bool
+    }
+
+    creation_inner_field! {
+        #[doc = "Set IP protocol version of the BGP speaker."]
+        set_ip_version, with_ip_version -> ip_version: protocol::IpVersion
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the BGP speaker."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+}
+
+impl IntoFallibleIterator for BgpSpeakerQuery {
+    type Item = BgpSpeaker;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<BgpSpeakerQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<BgpSpeaker> for BgpSpeakerRef {
+    fn from(value: BgpSpeaker) -> BgpSpeakerRef {
+        BgpSpeakerRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a BgpSpeaker> for BgpSpeakerRef {
+    fn from(value: &'a BgpSpeaker) -> BgpSpeakerRef {
+        BgpSpeakerRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for BgpSpeakerRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<BgpSpeakerRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            BgpSpeakerRef::new_verified(api::get_bgp_speaker(session, &self.value)?.id)
+        })
+    }
+}
@@ -45,6 +45,7 @@ pub struct FloatingIpQuery {
     session: Rc<Session>,
     query: Query,
     can_paginate: bool,
+    associated: Option<bool>,
     floating_network: Option<NetworkRef>,
     port: Option<PortRef>,
 }
@@ -75,6 +76,17 @@ impl FloatingIp {
         Ok(FloatingIp::new(session, inner))
     }
 
+    /// Find a floating IP on the network that is not associated with any port.
+    pub(crate) fn find_free(
+        session: Rc<Session>,
+        floating_network: NetworkRef,
+    ) -> Result<Option<FloatingIp>> {
+        FloatingIpQuery::unassociated(session)
+            .with_floating_network(floating_network)
+            .into_iter()
+            .next()
+    }
+
     transparent_property! {
         #[doc = "Creation data and time (if available)."]
         created_at: Option<DateTime<FixedOffset>>
@@ -252,17 +264,25 @@ impl Refresh for FloatingIp {
     }
 }
 
+taggable!(FloatingIp, "floatingips");
+
 impl FloatingIpQuery {
     pub(crate) fn new(session: Rc<Session>) -> FloatingIpQuery {
         FloatingIpQuery {
             session,
             query: Query::new(),
             can_paginate: true,
+            associated: None,
             floating_network: None,
             port: None,
         }
     }
 
+    /// Create a query that only returns floating IPs not associated with a port.
+    pub(crate) fn unassociated(session: Rc<Session>) -> FloatingIpQuery {
+        FloatingIpQuery::new(session).with_associated(false)
+    }
+
     /// Add marker to the request.
     ///
     /// Using this disables automatic pagination.
@@ -289,6 +309,20 @@ impl FloatingIpQuery {
         self
     }
 
+    /// Filter by whether the floating IP is associated with a port.
+    ///
+    /// All floating IPs are still fetched from the server, the ones that do
+    /// not match are dropped locally.
+    pub fn set_associated(&mut self, value: bool) {
+        self.associated = Some(value);
+    }
+
+    /// Filter by whether the floating IP is associated with a port.
+    pub fn with_associated(mut self, value: bool) -> Self {
+        self.set_associated(value);
+        self
+    }
+
     query_filter! {
         #[doc = "Filter by description."]
         set_description, with_description -> description
@@ -320,7 +354,7 @@ impl FloatingIpQuery {
         self.port = Some(value.into());
     }
 
-    /// Filter by network.
+    /// Filter by port.
     pub fn with_port<N: Into<PortRef>>(mut self, value: N) -> Self {
         self.set_port(value);
         self
@@ -335,7 +369,7 @@ impl FloatingIpQuery {
         self.query.push_str("router_id", value.into());
     }
 
-    /// Filter by network.
+    /// Filter by router.
     ///
     /// # Warning
     ///
@@ -398,11 +432,27 @@ impl ResourceQuery for FloatingIpQuery {
     }
 
     fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
         let query = self.query.with_marker_and_limit(limit, marker);
-        Ok(api::list_floating_ips(&self.session, &query)?
-            .into_iter()
-            .map(|item| FloatingIp::new(self.session.clone(), item))
-            .collect())
+        let (mut items, next_marker) = api::list_floating_ips(&self.session, &query)?;
+        if let Some(associated) = self.associated {
+            items.retain(|item| item.port_id.is_some() == associated);
+        }
+
+        Ok((
+            items
+                .into_iter()
+                .map(|item| FloatingIp::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
     }
 
     fn validate(&mut self) -> Result<()> {
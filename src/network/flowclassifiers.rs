@@ -0,0 +1,448 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Flow classifier management via Network API (service function chaining).
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    DeletionWaiter, FlowClassifierRef, IntoVerified, PortRef, Refresh, ResourceIterator,
+    ResourceQuery,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol};
+
+/// A query to flow classifier list.
+#[derive(Clone, Debug)]
+pub struct FlowClassifierQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single flow classifier.
+#[derive(Clone, Debug)]
+pub struct FlowClassifier {
+    session: Rc<Session>,
+    inner: protocol::FlowClassifier,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a flow classifier.
+#[derive(Clone, Debug)]
+pub struct NewFlowClassifier {
+    session: Rc<Session>,
+    inner: protocol::FlowClassifier,
+    logical_destination_port: Option<PortRef>,
+    logical_source_port: Option<PortRef>,
+}
+
+impl FlowClassifier {
+    /// Create a flow classifier object.
+    fn new(session: Rc<Session>, inner: protocol::FlowClassifier) -> FlowClassifier {
+        FlowClassifier {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a FlowClassifier object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<FlowClassifier> {
+        let inner = api::get_flow_classifier(&session, id)?;
+        Ok(FlowClassifier::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Flow classifier description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Destination IP prefix to match (if any)."]
+        destination_ip_prefix: Option<ipnet::IpNet>
+    }
+
+    transparent_property! {
+        #[doc = "Upper bound of the destination port range to match (if any)."]
+        destination_port_range_max: Option<u16>
+    }
+
+    transparent_property! {
+        #[doc = "Lower bound of the destination port range to match (if any)."]
+        destination_port_range_min: Option<u16>
+    }
+
+    transparent_property! {
+        #[doc = "Ethernet type to match."]
+        ethertype: Option<protocol::Ethertype>
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "ID of the destination Neutron port to match (if any)."]
+        logical_destination_port: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "ID of the source Neutron port to match (if any)."]
+        logical_source_port: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Flow classifier name."]
+        name: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "IP protocol to match (if any)."]
+        protocol: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Source IP prefix to match (if any)."]
+        source_ip_prefix: Option<ipnet::IpNet>
+    }
+
+    transparent_property! {
+        #[doc = "Upper bound of the source port range to match (if any)."]
+        source_port_range_max: Option<u16>
+    }
+
+    transparent_property! {
+        #[doc = "Lower bound of the source port range to match (if any)."]
+        source_port_range_min: Option<u16>
+    }
+
+    /// Delete the flow classifier.
+    pub fn delete(self) -> Result<DeletionWaiter<FlowClassifier>> {
+        api::delete_flow_classifier(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the flow classifier is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the flow classifier.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::FlowClassifierUpdate::default();
+        save_option_fields! {
+            self -> update: description name
+        };
+        let inner = api::update_flow_classifier(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for FlowClassifier {
+    /// Refresh the flow classifier.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_flow_classifier_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl FlowClassifierQuery {
+    pub(crate) fn new(session: Rc<Session>) -> FlowClassifierQuery {
+        FlowClassifierQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by flow classifier name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<FlowClassifierQuery> {
+        debug!("Fetching flow classifiers with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<FlowClassifier>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<FlowClassifier> {
+        debug!("Fetching one flow classifier with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for FlowClassifierQuery {
+    type Item = FlowClassifier;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_flow_classifiers(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| FlowClassifier::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl NewFlowClassifier {
+    /// Start creating a flow classifier.
+    pub(crate) fn new(session: Rc<Session>) -> NewFlowClassifier {
+        NewFlowClassifier {
+            session,
+            inner: protocol::FlowClassifier {
+                description: None,
+                destination_ip_prefix: None,
+                destination_port_range_max: None,
+                destination_port_range_min: None,
+                ethertype: None,
+                id: String::new(),
+                logical_destination_port: None,
+                logical_source_port: None,
+                name: None,
+                project_id: None,
+                protocol: None,
+                source_ip_prefix: None,
+                source_port_range_max: None,
+                source_port_range_min: None,
+            },
+            logical_destination_port: None,
+            logical_source_port: None,
+        }
+    }
+
+    /// Request creation of the flow classifier.
+    pub fn create(mut self) -> Result<FlowClassifier> {
+        if let Some(port) = self.logical_destination_port {
+            self.inner.logical_destination_port = Some(port.into_verified(&self.session)?.into());
+        }
+        if let Some(port) = self.logical_source_port {
+            self.inner.logical_source_port = Some(port.into_verified(&self.session)?.into());
+        }
+        let inner = api::create_flow_classifier(&self.session, self.inner)?;
+        Ok(FlowClassifier::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the flow classifier."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the destination IP prefix to match."]
+        set_destination_ip_prefix, with_destination_ip_prefix
+            -> destination_ip_prefix: optional ipnet::IpNet
+    }
+
+    /// Set the destination port range to match.
+    pub fn set_destination_port_range(&mut self, min: u16, max: u16) {
+        self.inner.destination_port_range_min = Some(min);
+        self.inner.destination_port_range_max = Some(max);
+    }
+
+    /// Set the destination port range to match.
+    #[inline]
+    pub fn with_destination_port_range(mut self, min: u16, max: u16) -> Self {
+        self.set_destination_port_range(min, max);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the Ethernet type to match."]
+        set_ethertype, with_ethertype -> ethertype: optional protocol::Ethertype
+    }
+
+    /// Set the destination Neutron port to match.
+    pub fn set_logical_destination_port<P: Into<PortRef>>(&mut self, value: P) {
+        self.logical_destination_port = Some(value.into());
+    }
+
+    /// Set the destination Neutron port to match.
+    pub fn with_logical_destination_port<P: Into<PortRef>>(mut self, value: P) -> Self {
+        self.set_logical_destination_port(value);
+        self
+    }
+
+    /// Set the source Neutron port to match.
+    pub fn set_logical_source_port<P: Into<PortRef>>(&mut self, value: P) {
+        self.logical_source_port = Some(value.into());
+    }
+
+    /// Set the source Neutron port to match.
+    pub fn with_logical_source_port<P: Into<PortRef>>(mut self, value: P) -> Self {
+        self.set_logical_source_port(value);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a name for the flow classifier."]
+        set_name, with_name -> name: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the flow classifier."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the IP protocol to match (e.g. `tcp`)."]
+        set_protocol, with_protocol -> protocol: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the source IP prefix to match."]
+        set_source_ip_prefix, with_source_ip_prefix -> source_ip_prefix: optional ipnet::IpNet
+    }
+
+    /// Set the source port range to match.
+    pub fn set_source_port_range(&mut self, min: u16, max: u16) {
+        self.inner.source_port_range_min = Some(min);
+        self.inner.source_port_range_max = Some(max);
+    }
+
+    /// Set the source port range to match.
+    #[inline]
+    pub fn with_source_port_range(mut self, min: u16, max: u16) -> Self {
+        self.set_source_port_range(min, max);
+        self
+    }
+}
+
+impl IntoFallibleIterator for FlowClassifierQuery {
+    type Item = FlowClassifier;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<FlowClassifierQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<FlowClassifier> for FlowClassifierRef {
+    fn from(value: FlowClassifier) -> FlowClassifierRef {
+        FlowClassifierRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a FlowClassifier> for FlowClassifierRef {
+    fn from(value: &'a FlowClassifier) -> FlowClassifierRef {
+        FlowClassifierRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for FlowClassifierRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<FlowClassifierRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            FlowClassifierRef::new_verified(api::get_flow_classifier(session, &self.value)?.id)
+        })
+    }
+}
@@ -0,0 +1,394 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! IKE policy management via Network API.
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    DeletionWaiter, IkePolicyRef, IntoVerified, Refresh, ResourceIterator, ResourceQuery,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol};
+
+/// A query to IKE policy list.
+#[derive(Clone, Debug)]
+pub struct IkePolicyQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single IKE policy.
+#[derive(Clone, Debug)]
+pub struct IkePolicy {
+    session: Rc<Session>,
+    inner: protocol::IkePolicy,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create an IKE policy.
+#[derive(Clone, Debug)]
+pub struct NewIkePolicy {
+    session: Rc<Session>,
+    inner: protocol::IkePolicy,
+}
+
+impl IkePolicy {
+    /// Create an IKE policy object.
+    fn new(session: Rc<Session>, inner: protocol::IkePolicy) -> IkePolicy {
+        IkePolicy {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load an IkePolicy object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<IkePolicy> {
+        let inner = api::get_ike_policy(&session, id)?;
+        Ok(IkePolicy::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Authentication algorithm."]
+        auth_algorithm: Option<protocol::VpnAuthAlgorithm>
+    }
+
+    update_field! {
+        #[doc = "Update the authentication algorithm."]
+        set_auth_algorithm, with_auth_algorithm -> auth_algorithm: optional protocol::VpnAuthAlgorithm
+    }
+
+    transparent_property! {
+        #[doc = "IKE policy description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Encryption algorithm."]
+        encryption_algorithm: Option<protocol::VpnEncryptionAlgorithm>
+    }
+
+    update_field! {
+        #[doc = "Update the encryption algorithm."]
+        set_encryption_algorithm, with_encryption_algorithm -> encryption_algorithm: optional protocol::VpnEncryptionAlgorithm
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "IKE protocol version."]
+        ike_version: Option<protocol::IkeVersion>
+    }
+
+    update_field! {
+        #[doc = "Update the IKE protocol version."]
+        set_ike_version, with_ike_version -> ike_version: optional protocol::IkeVersion
+    }
+
+    transparent_property! {
+        #[doc = "Lifetime of the security association."]
+        lifetime: ref Option<protocol::VpnLifetime>
+    }
+
+    update_field! {
+        #[doc = "Update the lifetime of the security association."]
+        set_lifetime, with_lifetime -> lifetime: optional protocol::VpnLifetime
+    }
+
+    transparent_property! {
+        #[doc = "IKE policy name."]
+        name: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Perfect forward secrecy group."]
+        pfs: Option<protocol::PfsGroup>
+    }
+
+    update_field! {
+        #[doc = "Update the perfect forward secrecy group."]
+        set_pfs, with_pfs -> pfs: optional protocol::PfsGroup
+    }
+
+    transparent_property! {
+        #[doc = "IKE phase 1 negotiation mode."]
+        phase1_negotiation_mode: Option<protocol::Phase1NegotiationMode>
+    }
+
+    update_field! {
+        #[doc = "Update the IKE phase 1 negotiation mode."]
+        set_phase1_negotiation_mode, with_phase1_negotiation_mode -> phase1_negotiation_mode: optional protocol::Phase1NegotiationMode
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    /// Delete the IKE policy.
+    pub fn delete(self) -> Result<DeletionWaiter<IkePolicy>> {
+        api::delete_ike_policy(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the IKE policy is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the IKE policy.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::IkePolicyUpdate::default();
+        save_option_fields! {
+            self -> update: auth_algorithm description encryption_algorithm ike_version
+                lifetime name pfs phase1_negotiation_mode
+        };
+        let inner = api::update_ike_policy(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for IkePolicy {
+    /// Refresh the IKE policy.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_ike_policy_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl IkePolicyQuery {
+    pub(crate) fn new(session: Rc<Session>) -> IkePolicyQuery {
+        IkePolicyQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by IKE policy name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<IkePolicyQuery> {
+        debug!("Fetching IKE policies with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<IkePolicy>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<IkePolicy> {
+        debug!("Fetching one IKE policy with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for IkePolicyQuery {
+    type Item = IkePolicy;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_ike_policies(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| IkePolicy::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl NewIkePolicy {
+    /// Start creating an IKE policy.
+    pub(crate) fn new(session: Rc<Session>) -> NewIkePolicy {
+        NewIkePolicy {
+            session,
+            inner: protocol::IkePolicy::default(),
+        }
+    }
+
+    /// Request creation of the IKE policy.
+    pub fn create(self) -> Result<IkePolicy> {
+        let inner = api::create_ike_policy(&self.session, self.inner)?;
+        Ok(IkePolicy::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the authentication algorithm."]
+        set_auth_algorithm, with_auth_algorithm -> auth_algorithm: optional protocol::VpnAuthAlgorithm
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the IKE policy."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the encryption algorithm."]
+        set_encryption_algorithm, with_encryption_algorithm -> encryption_algorithm: optional protocol::VpnEncryptionAlgorithm
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the IKE protocol version."]
+        set_ike_version, with_ike_version -> ike_version: optional protocol::IkeVersion
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the lifetime of the security association."]
+        set_lifetime, with_lifetime -> lifetime: optional protocol::VpnLifetime
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a name for the IKE policy."]
+        set_name, with_name -> name: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the perfect forward secrecy group."]
+        set_pfs, with_pfs -> pfs: optional protocol::PfsGroup
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the IKE phase 1 negotiation mode."]
+        set_phase1_negotiation_mode, with_phase1_negotiation_mode -> phase1_negotiation_mode: optional protocol::Phase1NegotiationMode
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the IKE policy."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+}
+
+impl IntoFallibleIterator for IkePolicyQuery {
+    type Item = IkePolicy;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<IkePolicyQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<IkePolicy> for IkePolicyRef {
+    fn from(value: IkePolicy) -> IkePolicyRef {
+        IkePolicyRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a IkePolicy> for IkePolicyRef {
+    fn from(value: &'a IkePolicy) -> IkePolicyRef {
+        IkePolicyRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for IkePolicyRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<IkePolicyRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            IkePolicyRef::new_verified(api::get_ike_policy(session, &self.value)?.id)
+        })
+    }
+}
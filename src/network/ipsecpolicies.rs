@@ -0,0 +1,394 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! IPsec policy management via Network API.
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    DeletionWaiter, IntoVerified, IpsecPolicyRef, Refresh, ResourceIterator, ResourceQuery,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol};
+
+/// A query to IPsec policy list.
+#[derive(Clone, Debug)]
+pub struct IpsecPolicyQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single IPsec policy.
+#[derive(Clone, Debug)]
+pub struct IpsecPolicy {
+    session: Rc<Session>,
+    inner: protocol::IpsecPolicy,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create an IPsec policy.
+#[derive(Clone, Debug)]
+pub struct NewIpsecPolicy {
+    session: Rc<Session>,
+    inner: protocol::IpsecPolicy,
+}
+
+impl IpsecPolicy {
+    /// Create an IPsec policy object.
+    fn new(session: Rc<Session>, inner: protocol::IpsecPolicy) -> IpsecPolicy {
+        IpsecPolicy {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load an IpsecPolicy object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<IpsecPolicy> {
+        let inner = api::get_ipsec_policy(&session, id)?;
+        Ok(IpsecPolicy::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Authentication algorithm."]
+        auth_algorithm: Option<protocol::VpnAuthAlgorithm>
+    }
+
+    update_field! {
+        #[doc = "Update the authentication algorithm."]
+        set_auth_algorithm, with_auth_algorithm -> auth_algorithm: optional protocol::VpnAuthAlgorithm
+    }
+
+    transparent_property! {
+        #[doc = "IPsec policy description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Encapsulation mode."]
+        encapsulation_mode: Option<protocol::IpsecEncapsulationMode>
+    }
+
+    update_field! {
+        #[doc = "Update the encapsulation mode."]
+        set_encapsulation_mode, with_encapsulation_mode -> encapsulation_mode: optional protocol::IpsecEncapsulationMode
+    }
+
+    transparent_property! {
+        #[doc = "Encryption algorithm."]
+        encryption_algorithm: Option<protocol::VpnEncryptionAlgorithm>
+    }
+
+    update_field! {
+        #[doc = "Update the encryption algorithm."]
+        set_encryption_algorithm, with_encryption_algorithm -> encryption_algorithm: optional protocol::VpnEncryptionAlgorithm
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Lifetime of the security association."]
+        lifetime: ref Option<protocol::VpnLifetime>
+    }
+
+    update_field! {
+        #[doc = "Update the lifetime of the security association."]
+        set_lifetime, with_lifetime -> lifetime: optional protocol::VpnLifetime
+    }
+
+    transparent_property! {
+        #[doc = "IPsec policy name."]
+        name: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Perfect forward secrecy group."]
+        pfs: Option<protocol::PfsGroup>
+    }
+
+    update_field! {
+        #[doc = "Update the perfect forward secrecy group."]
+        set_pfs, with_pfs -> pfs: optional protocol::PfsGroup
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Transform protocol."]
+        transform_protocol: Option<protocol::IpsecTransformProtocol>
+    }
+
+    update_field! {
+        #[doc = "Update the transform protocol."]
+        set_transform_protocol, with_transform_protocol -> transform_protocol: optional protocol::IpsecTransformProtocol
+    }
+
+    /// Delete the IPsec policy.
+    pub fn delete(self) -> Result<DeletionWaiter<IpsecPolicy>> {
+        api::delete_ipsec_policy(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the IPsec policy is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the IPsec policy.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::IpsecPolicyUpdate::default();
+        save_option_fields! {
+            self -> update: auth_algorithm description encapsulation_mode encryption_algorithm
+                lifetime name pfs transform_protocol
+        };
+        let inner = api::update_ipsec_policy(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for IpsecPolicy {
+    /// Refresh the IPsec policy.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_ipsec_policy_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl IpsecPolicyQuery {
+    pub(crate) fn new(session: Rc<Session>) -> IpsecPolicyQuery {
+        IpsecPolicyQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by IPsec policy name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<IpsecPolicyQuery> {
+        debug!("Fetching IPsec policies with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<IpsecPolicy>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<IpsecPolicy> {
+        debug!("Fetching one IPsec policy with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for IpsecPolicyQuery {
+    type Item = IpsecPolicy;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_ipsec_policies(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| IpsecPolicy::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl NewIpsecPolicy {
+    /// Start creating an IPsec policy.
+    pub(crate) fn new(session: Rc<Session>) -> NewIpsecPolicy {
+        NewIpsecPolicy {
+            session,
+            inner: protocol::IpsecPolicy::default(),
+        }
+    }
+
+    /// Request creation of the IPsec policy.
+    pub fn create(self) -> Result<IpsecPolicy> {
+        let inner = api::create_ipsec_policy(&self.session, self.inner)?;
+        Ok(IpsecPolicy::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the authentication algorithm."]
+        set_auth_algorithm, with_auth_algorithm -> auth_algorithm: optional protocol::VpnAuthAlgorithm
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the IPsec policy."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the encapsulation mode."]
+        set_encapsulation_mode, with_encapsulation_mode -> encapsulation_mode: optional protocol::IpsecEncapsulationMode
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the encryption algorithm."]
+        set_encryption_algorithm, with_encryption_algorithm -> encryption_algorithm: optional protocol::VpnEncryptionAlgorithm
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the lifetime of the security association."]
+        set_lifetime, with_lifetime -> lifetime: optional protocol::VpnLifetime
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a name for the IPsec policy."]
+        set_name, with_name -> name: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the perfect forward secrecy group."]
+        set_pfs, with_pfs -> pfs: optional protocol::PfsGroup
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the IPsec policy."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the transform protocol."]
+        set_transform_protocol, with_transform_protocol -> transform_protocol: optional protocol::IpsecTransformProtocol
+    }
+}
+
+impl IntoFallibleIterator for IpsecPolicyQuery {
+    type Item = IpsecPolicy;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<IpsecPolicyQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<IpsecPolicy> for IpsecPolicyRef {
+    fn from(value: IpsecPolicy) -> IpsecPolicyRef {
+        IpsecPolicyRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a IpsecPolicy> for IpsecPolicyRef {
+    fn from(value: &'a IpsecPolicy) -> IpsecPolicyRef {
+        IpsecPolicyRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for IpsecPolicyRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<IpsecPolicyRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            IpsecPolicyRef::new_verified(api::get_ipsec_policy(session, &self.value)?.id)
+        })
+    }
+}
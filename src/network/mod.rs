@@ -14,21 +14,116 @@
 
 //! Network API implementation bits.
 
+/// Implement `Taggable` for a resource with `session` and `inner.id` fields.
+///
+/// With `cached <field>`, the `Option<Vec<String>>` field of `inner` is kept
+/// in sync with successful changes.
+macro_rules! taggable {
+    ($cls:ident, $resource_type:expr $(, cached $field:ident)?) => {
+        impl $crate::common::Taggable for $cls {
+            fn tags(&self) -> $crate::Result<Vec<String>> {
+                $crate::network::api::list_tags(&self.session, $resource_type, &self.inner.id)
+            }
+
+            fn add_tag(&mut self, tag: &str) -> $crate::Result<()> {
+                $crate::network::api::add_tag(&self.session, $resource_type, &self.inner.id, tag)?;
+                $(
+                    if let Some(ref mut tags) = self.inner.$field {
+                        if !tags.iter().any(|item| item == tag) {
+                            tags.push(String::from(tag));
+                        }
+                    }
+                )?
+                Ok(())
+            }
+
+            fn replace_tags(&mut self, tags: &[&str]) -> $crate::Result<()> {
+                let tags: Vec<String> = tags.iter().map(|&tag| String::from(tag)).collect();
+                $crate::network::api::replace_tags(
+                    &self.session,
+                    $resource_type,
+                    &self.inner.id,
+                    tags.clone(),
+                )?;
+                $(self.inner.$field = Some(tags);)?
+                Ok(())
+            }
+
+            fn delete_tag(&mut self, tag: &str) -> $crate::Result<()> {
+                $crate::network::api::delete_tag(
+                    &self.session,
+                    $resource_type,
+                    &self.inner.id,
+                    tag,
+                )?;
+                $(
+                    if let Some(ref mut tags) = self.inner.$field {
+                        tags.retain(|item| item != tag);
+                    }
+                )?
+                Ok(())
+            }
+
+            fn delete_all_tags(&mut self) -> $crate::Result<()> {
+                $crate::network::api::delete_all_tags(
+                    &self.session,
+                    $resource_type,
+                    &self.inner.id,
+                )?;
+                $(self.inner.$field = Some(Vec::new());)?
+                Ok(())
+            }
+        }
+    };
+}
+
+mod addressscopes;
+mod agents;
 mod api;
+mod bgppeers;
+mod bgpspeakers;
 mod floatingips;
+mod flowclassifiers;
+mod ikepolicies;
+mod ipsecpolicies;
 mod networks;
+mod portchains;
+mod portpairgroups;
+mod portpairs;
 mod ports;
 mod protocol;
 mod routers;
+mod segments;
+mod siteconnections;
+mod subnetpools;
 mod subnets;
+mod vpnservices;
 
+pub use self::addressscopes::{AddressScope, AddressScopeQuery, NewAddressScope};
+pub use self::agents::{NetworkAgent, NetworkAgentQuery};
+pub use self::bgppeers::{BgpPeer, BgpPeerQuery, NewBgpPeer};
+pub use self::bgpspeakers::{BgpSpeaker, BgpSpeakerQuery, NewBgpSpeaker};
 pub use self::floatingips::{FloatingIp, FloatingIpQuery, NewFloatingIp};
+pub use self::flowclassifiers::{FlowClassifier, FlowClassifierQuery, NewFlowClassifier};
+pub use self::ikepolicies::{IkePolicy, IkePolicyQuery, NewIkePolicy};
+pub use self::ipsecpolicies::{IpsecPolicy, IpsecPolicyQuery, NewIpsecPolicy};
 pub use self::networks::{Network, NetworkQuery, NewNetwork};
+pub use self::portchains::{NewPortChain, PortChain, PortChainQuery};
+pub use self::portpairgroups::{NewPortPairGroup, PortPairGroup, PortPairGroupQuery};
+pub use self::portpairs::{NewPortPair, PortPair, PortPairQuery};
 pub use self::ports::{NewPort, Port, PortIpAddress, PortIpRequest, PortQuery};
 pub use self::protocol::{
-    AllocationPool, AllowedAddressPair, ExternalGateway, FloatingIpSortKey, FloatingIpStatus,
-    HostRoute, IpVersion, Ipv6Mode, NetworkSortKey, NetworkStatus, PortExtraDhcpOption,
-    PortForwarding, PortSortKey, RouterSortKey, RouterStatus, SubnetSortKey,
+    AllocationPool, AllowedAddressPair, BgpAuthType, BgpRoute, ChainParameters, DeadPeerDetection,
+    DeadPeerDetectionAction, Ethertype, ExternalGateway, FloatingIpSortKey, FloatingIpStatus,
+    HostRoute, IkeVersion, IpVersion, IpsecEncapsulationMode, IpsecTransformProtocol, Ipv6Mode,
+    NetworkSortKey, NetworkStatus, NetworkType, PfsGroup, Phase1NegotiationMode,
+    PortExtraDhcpOption, PortForwarding, PortSortKey, RouterSortKey, RouterStatus,
+    ServiceFunctionParameters, SfcCorrelation, SiteConnectionInitiator, SubnetSortKey,
+    VpnAuthAlgorithm, VpnEncryptionAlgorithm, VpnLifetime, VpnStatus,
 };
 pub use self::routers::{NewRouter, Router, RouterQuery};
+pub use self::segments::{NetworkSegment, NetworkSegmentQuery, NewNetworkSegment};
+pub use self::siteconnections::{NewSiteConnection, SiteConnection, SiteConnectionQuery};
+pub use self::subnetpools::{NewSubnetPool, SubnetPool, SubnetPoolQuery};
 pub use self::subnets::{NewSubnet, Subnet, SubnetQuery};
+pub use self::vpnservices::{NewVpnService, VpnService, VpnServiceQuery};
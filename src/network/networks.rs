@@ -27,7 +27,10 @@ use super::super::common::{
 use super::super::session::Session;
 use super::super::utils::Query;
 use super::super::{Error, Result, Sort};
-use super::{api, protocol};
+use super::{
+    api, protocol, NetworkAgent, NetworkSegment, NetworkSegmentQuery, NewNetworkSegment,
+    SubnetQuery,
+};
 
 /// A query to network list.
 #[derive(Clone, Debug)]
@@ -78,6 +81,11 @@ impl Network {
         set_admin_state_up, with_admin_state_up -> admin_state_up: bool
     }
 
+    transparent_property! {
+        #[doc = "Availability zone candidates for the network."]
+        availability_zone_hints: ref Vec<String>
+    }
+
     transparent_property! {
         #[doc = "The availability zones for the network (if available)."]
         availability_zones: ref Vec<String>
@@ -118,6 +126,13 @@ impl Network {
         set_external, with_external -> external: optional bool
     }
 
+    /// A field returned by the Network API that has no dedicated accessor.
+    ///
+    /// Useful for attributes added by Network API extensions.
+    pub fn extra_field(&self, key: &str) -> Option<&serde_json::Value> {
+        self.inner.extra.get(key)
+    }
+
     transparent_property! {
         #[doc = "Unique ID."]
         id: ref String
@@ -179,11 +194,28 @@ impl Network {
         set_shared, with_shared -> shared: bool
     }
 
+    /// Fetch the segments of this network.
+    pub fn segments(&self) -> Result<Vec<NetworkSegment>> {
+        NetworkSegmentQuery::new(self.session.clone())
+            .with_network(self)
+            .all()
+    }
+
     transparent_property! {
         #[doc = "Status of the network."]
         status: protocol::NetworkStatus
     }
 
+    /// IDs of the subnets of this network.
+    pub fn subnet_ids(&self) -> &Vec<String> {
+        &self.inner.subnets
+    }
+
+    /// Build a query against the subnets of this network.
+    pub fn subnets(&self) -> SubnetQuery {
+        SubnetQuery::new(self.session.clone()).with_network(self)
+    }
+
     transparent_property! {
         #[doc = "Last update data and time (if available)."]
         updated_at: Option<DateTime<FixedOffset>>
@@ -194,6 +226,26 @@ impl Network {
         vlan_transparent: Option<bool>
     }
 
+    /// Schedule the network to a DHCP agent (requires administrator rights).
+    pub fn add_dhcp_agent<S: AsRef<str>>(&self, agent_id: S) -> Result<()> {
+        api::add_network_to_dhcp_agent(&self.session, agent_id, &self.inner.id)
+    }
+
+    /// List DHCP agents hosting the network (requires administrator rights).
+    pub fn dhcp_agents(&self) -> Result<Vec<NetworkAgent>> {
+        Ok(
+            api::list_network_dhcp_agents(&self.session, &self.inner.id)?
+                .into_iter()
+                .map(|item| NetworkAgent::new(self.session.clone(), item))
+                .collect(),
+        )
+    }
+
+    /// Remove the network from a DHCP agent (requires administrator rights).
+    pub fn remove_dhcp_agent<S: AsRef<str>>(&self, agent_id: S) -> Result<()> {
+        api::remove_network_from_dhcp_agent(&self.session, agent_id, &self.inner.id)
+    }
+
     /// Delete the network.
     pub fn delete(self) -> Result<DeletionWaiter<Network>> {
         api::delete_network(&self.session, &self.inner.id)?;
@@ -235,6 +287,8 @@ impl Refresh for Network {
     }
 }
 
+taggable!(Network, "networks");
+
 impl NetworkQuery {
     pub(crate) fn new(session: Rc<Session>) -> NetworkQuery {
         NetworkQuery {
@@ -244,6 +298,11 @@ impl NetworkQuery {
         }
     }
 
+    /// Create a query that only returns external networks.
+    pub(crate) fn external_networks(session: Rc<Session>) -> NetworkQuery {
+        NetworkQuery::new(session).with_external(true)
+    }
+
     /// Add marker to the request.
     ///
     /// Using this disables automatic pagination.
@@ -270,12 +329,53 @@ impl NetworkQuery {
         self
     }
 
+    /// Filter by whether the network is external.
+    pub fn set_external(&mut self, value: bool) {
+        self.query.push("router:external", value);
+    }
+
+    /// Filter by whether the network is external.
+    #[inline]
+    pub fn with_external(mut self, value: bool) -> Self {
+        self.set_external(value);
+        self
+    }
+
     /// Filter by network name (a database regular expression).
     pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
         self.query.push_str("name", value);
         self
     }
 
+    query_filter! {
+        #[doc = "Filter by whether the network is shared between projects."]
+        set_shared, with_shared -> shared: bool
+    }
+
+    /// Filter by tags (networks must have all of them).
+    pub fn with_tags(mut self, tags: &[&str]) -> Self {
+        self.query.push_str("tags", tags.join(","));
+        self
+    }
+
+    /// Filter by tags (networks must have at least one of them).
+    pub fn with_any_tags(mut self, tags: &[&str]) -> Self {
+        self.query.push_str("tags-any", tags.join(","));
+        self
+    }
+
+    /// Exclude networks that have all of the provided tags.
+    pub fn without_tags(mut self, tags: &[&str]) -> Self {
+        self.query.push_str("not-tags", tags.join(","));
+        self
+    }
+
+    /// Exclude networks that have at least one of the provided tags.
+    pub fn without_any_tags(mut self, tags: &[&str]) -> Self {
+        self.query.push_str("not-tags-any", tags.join(","));
+        self
+    }
+
     /// Convert this query into an iterator executing the request.
     ///
     /// Returns a `FallibleIterator`, which is an iterator with each `next`
@@ -324,11 +424,23 @@ impl ResourceQuery for NetworkQuery {
     }
 
     fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
         let query = self.query.with_marker_and_limit(limit, marker);
-        Ok(api::list_networks(&self.session, &query)?
-            .into_iter()
-            .map(|item| Network::new(self.session.clone(), item))
-            .collect())
+        let (items, next_marker) = api::list_networks(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| Network::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
     }
 }
 
@@ -352,6 +464,11 @@ impl NewNetwork {
         set_admin_state_up, with_admin_state_up -> admin_state_up: bool
     }
 
+    creation_inner_vec! {
+        #[doc = "Availability zone candidate(s) for the network."]
+        add_availability_zone_hint, with_availability_zone_hint -> availability_zone_hints
+    }
+
     creation_inner_field! {
         #[doc = "Configure whether this network is default."]
         set_default, with_default -> is_default: optional bool
@@ -388,6 +505,18 @@ impl NewNetwork {
             -> port_security_enabled: optional bool
     }
 
+    /// Add a provider segment to the network (requires administrator rights).
+    pub fn add_segment(&mut self, segment: NewNetworkSegment) {
+        self.inner.segments.push(segment.inner);
+    }
+
+    /// Add a provider segment to the network (requires administrator rights).
+    #[inline]
+    pub fn with_segment(mut self, segment: NewNetworkSegment) -> Self {
+        self.add_segment(segment);
+        self
+    }
+
     creation_inner_field! {
         #[doc = "Configure whether the network is shared across all projects."]
         set_shared, with_shared
@@ -419,6 +548,12 @@ impl From<Network> for NetworkRef {
     }
 }
 
+impl<'a> From<&'a Network> for NetworkRef {
+    fn from(value: &'a Network) -> NetworkRef {
+        NetworkRef::new_verified(value.inner.id.clone())
+    }
+}
+
 #[cfg(feature = "network")]
 impl IntoVerified for NetworkRef {
     /// Verify this reference and convert to an ID, if possible.
@@ -0,0 +1,406 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Port chain management via Network API (service function chaining).
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    DeletionWaiter, FlowClassifierRef, IntoVerified, PortChainRef, PortPairGroupRef, Refresh,
+    ResourceIterator, ResourceQuery,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol, FlowClassifier, PortPairGroup};
+
+/// A query to port chain list.
+#[derive(Clone, Debug)]
+pub struct PortChainQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single port chain.
+#[derive(Clone, Debug)]
+pub struct PortChain {
+    session: Rc<Session>,
+    inner: protocol::PortChain,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a port chain.
+#[derive(Clone, Debug)]
+pub struct NewPortChain {
+    session: Rc<Session>,
+    inner: protocol::PortChain,
+    flow_classifiers: Vec<FlowClassifierRef>,
+    port_pair_groups: Vec<PortPairGroupRef>,
+}
+
+impl PortChain {
+    /// Create a port chain object.
+    fn new(session: Rc<Session>, inner: protocol::PortChain) -> PortChain {
+        PortChain {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a PortChain object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<PortChain> {
+        let inner = api::get_port_chain(&session, id)?;
+        Ok(PortChain::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Chain ID used in the data path (if available)."]
+        chain_id: Option<u32>
+    }
+
+    transparent_property! {
+        #[doc = "Parameters of the chain (if available)."]
+        chain_parameters: Option<protocol::ChainParameters>
+    }
+
+    transparent_property! {
+        #[doc = "Port chain description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    /// IDs of the flow classifiers of this chain.
+    pub fn flow_classifier_ids(&self) -> &Vec<String> {
+        &self.inner.flow_classifiers
+    }
+
+    update_field! {
+        #[doc = "Update the flow classifiers of this chain."]
+        set_flow_classifier_ids, with_flow_classifier_ids -> flow_classifiers: Vec<String>
+    }
+
+    /// Fetch the flow classifiers of this chain.
+    pub fn flow_classifiers(&self) -> Result<Vec<FlowClassifier>> {
+        self.inner
+            .flow_classifiers
+            .iter()
+            .map(|id| FlowClassifier::load(self.session.clone(), id))
+            .collect()
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Port chain name."]
+        name: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name: optional String
+    }
+
+    /// IDs of the port pair groups of this chain (in order).
+    pub fn port_pair_group_ids(&self) -> &Vec<String> {
+        &self.inner.port_pair_groups
+    }
+
+    update_field! {
+        #[doc = "Update the port pair groups of this chain."]
+        set_port_pair_group_ids, with_port_pair_group_ids -> port_pair_groups: Vec<String>
+    }
+
+    /// Fetch the port pair groups of this chain (in order).
+    pub fn port_pair_groups(&self) -> Result<Vec<PortPairGroup>> {
+        self.inner
+            .port_pair_groups
+            .iter()
+            .map(|id| PortPairGroup::load(self.session.clone(), id))
+            .collect()
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    /// Delete the port chain.
+    pub fn delete(self) -> Result<DeletionWaiter<PortChain>> {
+        api::delete_port_chain(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the port chain is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the port chain.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::PortChainUpdate::default();
+        save_fields! {
+            self -> update: flow_classifiers port_pair_groups
+        };
+        save_option_fields! {
+            self -> update: description name
+        };
+        let inner = api::update_port_chain(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for PortChain {
+    /// Refresh the port chain.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_port_chain_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl PortChainQuery {
+    pub(crate) fn new(session: Rc<Session>) -> PortChainQuery {
+        PortChainQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by port chain name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<PortChainQuery> {
+        debug!("Fetching port chains with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<PortChain>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<PortChain> {
+        debug!("Fetching one port chain with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for PortChainQuery {
+    type Item = PortChain;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_port_chains(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| PortChain::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl NewPortChain {
+    /// Start creating a port chain.
+    pub(crate) fn new(session: Rc<Session>) -> NewPortChain {
+        NewPortChain {
+            session,
+            inner: protocol::PortChain {
+                chain_id: None,
+                chain_parameters: None,
+                description: None,
+                // Will be replaced in create()
+                flow_classifiers: Vec::new(),
+                id: String::new(),
+                name: None,
+                // Will be replaced in create()
+                port_pair_groups: Vec::new(),
+                project_id: None,
+            },
+            flow_classifiers: Vec::new(),
+            port_pair_groups: Vec::new(),
+        }
+    }
+
+    /// Request creation of the port chain.
+    pub fn create(mut self) -> Result<PortChain> {
+        for flow_classifier in self.flow_classifiers {
+            let verified = flow_classifier.into_verified(&self.session)?;
+            self.inner.flow_classifiers.push(verified.into());
+        }
+        for port_pair_group in self.port_pair_groups {
+            let verified = port_pair_group.into_verified(&self.session)?;
+            self.inner.port_pair_groups.push(verified.into());
+        }
+        let inner = api::create_port_chain(&self.session, self.inner)?;
+        Ok(PortChain::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set parameters of the chain."]
+        set_chain_parameters, with_chain_parameters
+            -> chain_parameters: optional protocol::ChainParameters
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the port chain."]
+        set_description, with_description -> description: optional String
+    }
+
+    /// Add a flow classifier to the chain.
+    pub fn add_flow_classifier<F: Into<FlowClassifierRef>>(&mut self, value: F) {
+        self.flow_classifiers.push(value.into());
+    }
+
+    /// Add a flow classifier to the chain.
+    pub fn with_flow_classifier<F: Into<FlowClassifierRef>>(mut self, value: F) -> Self {
+        self.add_flow_classifier(value);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a name for the port chain."]
+        set_name, with_name -> name: optional String
+    }
+
+    /// Append a port pair group to the chain.
+    pub fn add_port_pair_group<G: Into<PortPairGroupRef>>(&mut self, value: G) {
+        self.port_pair_groups.push(value.into());
+    }
+
+    /// Append a port pair group to the chain.
+    pub fn with_port_pair_group<G: Into<PortPairGroupRef>>(mut self, value: G) -> Self {
+        self.add_port_pair_group(value);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the port chain."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+}
+
+impl IntoFallibleIterator for PortChainQuery {
+    type Item = PortChain;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<PortChainQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<PortChain> for PortChainRef {
+    fn from(value: PortChain) -> PortChainRef {
+        PortChainRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a PortChain> for PortChainRef {
+    fn from(value: &'a PortChain) -> PortChainRef {
+        PortChainRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for PortChainRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<PortChainRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            PortChainRef::new_verified(api::get_port_chain(session, &self.value)?.id)
+        })
+    }
+}
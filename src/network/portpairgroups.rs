@@ -0,0 +1,350 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Port pair group management via Network API (service function chaining).
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    DeletionWaiter, IntoVerified, PortPairGroupRef, PortPairRef, Refresh, ResourceIterator,
+    ResourceQuery,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol, PortPair};
+
+/// A query to port pair group list.
+#[derive(Clone, Debug)]
+pub struct PortPairGroupQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single port pair group.
+#[derive(Clone, Debug)]
+pub struct PortPairGroup {
+    session: Rc<Session>,
+    inner: protocol::PortPairGroup,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a port pair group.
+#[derive(Clone, Debug)]
+pub struct NewPortPairGroup {
+    session: Rc<Session>,
+    inner: protocol::PortPairGroup,
+    port_pairs: Vec<PortPairRef>,
+}
+
+impl PortPairGroup {
+    /// Create a port pair group object.
+    fn new(session: Rc<Session>, inner: protocol::PortPairGroup) -> PortPairGroup {
+        PortPairGroup {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a PortPairGroup object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<PortPairGroup> {
+        let inner = api::get_port_pair_group(&session, id)?;
+        Ok(PortPairGroup::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Port pair group description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Port pair group name."]
+        name: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name: optional String
+    }
+
+    /// IDs of the port pairs in this group.
+    pub fn port_pair_ids(&self) -> &Vec<String> {
+        &self.inner.port_pairs
+    }
+
+    update_field! {
+        #[doc = "Update the port pairs in this group."]
+        set_port_pair_ids, with_port_pair_ids -> port_pairs: Vec<String>
+    }
+
+    /// Fetch the port pairs in this group.
+    pub fn port_pairs(&self) -> Result<Vec<PortPair>> {
+        self.inner
+            .port_pairs
+            .iter()
+            .map(|id| PortPair::load(self.session.clone(), id))
+            .collect()
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    /// Delete the port pair group.
+    pub fn delete(self) -> Result<DeletionWaiter<PortPairGroup>> {
+        api::delete_port_pair_group(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the port pair group is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the port pair group.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::PortPairGroupUpdate::default();
+        save_fields! {
+            self -> update: port_pairs
+        };
+        save_option_fields! {
+            self -> update: description name
+        };
+        let inner = api::update_port_pair_group(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for PortPairGroup {
+    /// Refresh the port pair group.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_port_pair_group_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl PortPairGroupQuery {
+    pub(crate) fn new(session: Rc<Session>) -> PortPairGroupQuery {
+        PortPairGroupQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by port pair group name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<PortPairGroupQuery> {
+        debug!("Fetching port pair groups with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<PortPairGroup>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<PortPairGroup> {
+        debug!("Fetching one port pair group with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for PortPairGroupQuery {
+    type Item = PortPairGroup;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_port_pair_groups(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| PortPairGroup::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl NewPortPairGroup {
+    /// Start creating a port pair group.
+    pub(crate) fn new(session: Rc<Session>) -> NewPortPairGroup {
+        NewPortPairGroup {
+            session,
+            inner: protocol::PortPairGroup {
+                description: None,
+                id: String::new(),
+                name: None,
+                // Will be replaced in create()
+                port_pairs: Vec::new(),
+                project_id: None,
+            },
+            port_pairs: Vec::new(),
+        }
+    }
+
+    /// Request creation of the port pair group.
+    pub fn create(mut self) -> Result<PortPairGroup> {
+        for port_pair in self.port_pairs {
+            let verified = port_pair.into_verified(&self.session)?;
+            self.inner.port_pairs.push(verified.into());
+        }
+        let inner = api::create_port_pair_group(&self.session, self.inner)?;
+        Ok(PortPairGroup::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the port pair group."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a name for the port pair group."]
+        set_name, with_name -> name: optional String
+    }
+
+    /// Add a port pair to the group.
+    pub fn add_port_pair<P: Into<PortPairRef>>(&mut self, value: P) {
+        self.port_pairs.push(value.into());
+    }
+
+    /// Add a port pair to the group.
+    pub fn with_port_pair<P: Into<PortPairRef>>(mut self, value: P) -> Self {
+        self.add_port_pair(value);
+        self
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the port pair group."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+}
+
+impl IntoFallibleIterator for PortPairGroupQuery {
+    type Item = PortPairGroup;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<PortPairGroupQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<PortPairGroup> for PortPairGroupRef {
+    fn from(value: PortPairGroup) -> PortPairGroupRef {
+        PortPairGroupRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a PortPairGroup> for PortPairGroupRef {
+    fn from(value: &'a PortPairGroup) -> PortPairGroupRef {
+        PortPairGroupRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for PortPairGroupRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<PortPairGroupRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            PortPairGroupRef::new_verified(api::get_port_pair_group(session, &self.value)?.id)
+        })
+    }
+}
@@ -0,0 +1,354 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Port pair management via Network API (service function chaining).
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    DeletionWaiter, IntoVerified, PortPairRef, PortRef, Refresh, ResourceIterator, ResourceQuery,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol, Port};
+
+/// A query to port pair list.
+#[derive(Clone, Debug)]
+pub struct PortPairQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+}
+
+/// Structure representing a single port pair.
+#[derive(Clone, Debug)]
+pub struct PortPair {
+    session: Rc<Session>,
+    inner: protocol::PortPair,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a port pair.
+#[derive(Clone, Debug)]
+pub struct NewPortPair {
+    session: Rc<Session>,
+    inner: protocol::PortPair,
+    ingress_port: PortRef,
+    egress_port: PortRef,
+}
+
+impl PortPair {
+    /// Create a port pair object.
+    fn new(session: Rc<Session>, inner: protocol::PortPair) -> PortPair {
+        PortPair {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a PortPair object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<PortPair> {
+        let inner = api::get_port_pair(&session, id)?;
+        Ok(PortPair::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Port pair description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    /// Get the egress port.
+    pub fn egress_port(&self) -> Result<Port> {
+        Port::load(self.session.clone(), &self.inner.egress_port_id)
+    }
+
+    transparent_property! {
+        #[doc = "ID of the port traffic leaves the service function from."]
+        egress_port_id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    /// Get the ingress port.
+    pub fn ingress_port(&self) -> Result<Port> {
+        Port::load(self.session.clone(), &self.inner.ingress_port_id)
+    }
+
+    transparent_property! {
+        #[doc = "ID of the port traffic enters the service function through."]
+        ingress_port_id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Port pair name."]
+        name: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Parameters of the service function (if available)."]
+        service_function_parameters: Option<protocol::ServiceFunctionParameters>
+    }
+
+    /// Delete the port pair.
+    pub fn delete(self) -> Result<DeletionWaiter<PortPair>> {
+        api::delete_port_pair(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the port pair is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the port pair.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::PortPairUpdate::default();
+        save_option_fields! {
+            self -> update: description name
+        };
+        let inner = api::update_port_pair(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for PortPair {
+    /// Refresh the port pair.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_port_pair_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl PortPairQuery {
+    pub(crate) fn new(session: Rc<Session>) -> PortPairQuery {
+        PortPairQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by port pair name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<PortPairQuery> {
+        debug!("Fetching port pairs with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<PortPair>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<PortPair> {
+        debug!("Fetching one port pair with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for PortPairQuery {
+    type Item = PortPair;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_port_pairs(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| PortPair::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+}
+
+impl NewPortPair {
+    /// Start creating a port pair.
+    pub(crate) fn new(
+        session: Rc<Session>,
+        ingress_port: PortRef,
+        egress_port: PortRef,
+    ) -> NewPortPair {
+        NewPortPair {
+            session,
+            inner: protocol::PortPair {
+                description: None,
+                // Will be replaced in create()
+                egress_port_id: String::new(),
+                id: String::new(),
+                // Will be replaced in create()
+                ingress_port_id: String::new(),
+                name: None,
+                project_id: None,
+                service_function_parameters: None,
+            },
+            ingress_port,
+            egress_port,
+        }
+    }
+
+    /// Request creation of the port pair.
+    pub fn create(mut self) -> Result<PortPair> {
+        self.inner.ingress_port_id = self.ingress_port.into_verified(&self.session)?.into();
+        self.inner.egress_port_id = self.egress_port.into_verified(&self.session)?.into();
+        let inner = api::create_port_pair(&self.session, self.inner)?;
+        Ok(PortPair::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the port pair."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a name for the port pair."]
+        set_name, with_name -> name: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the port pair."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set parameters of the service function."]
+        set_service_function_parameters, with_service_function_parameters
+            -> service_function_parameters: optional protocol::ServiceFunctionParameters
+    }
+}
+
+impl IntoFallibleIterator for PortPairQuery {
+    type Item = PortPair;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<PortPairQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<PortPair> for PortPairRef {
+    fn from(value: PortPair) -> PortPairRef {
+        PortPairRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a PortPair> for PortPairRef {
+    fn from(value: &'a PortPair) -> PortPairRef {
+        PortPairRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for PortPairRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<PortPairRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            PortPairRef::new_verified(api::get_port_pair(session, &self.value)?.id)
+        })
+    }
+}
@@ -14,7 +14,7 @@
 
 //! Ports management via Port API.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::mem;
 use std::net;
 use std::rc::Rc;
@@ -28,6 +28,8 @@ use super::super::common::{
     DeletionWaiter, IntoVerified, NetworkRef, PortRef, Refresh, ResourceIterator, ResourceQuery,
     SecurityGroupRef, SubnetRef,
 };
+#[cfg(feature = "compute")]
+use super::super::compute::Server;
 use super::super::session::Session;
 use super::super::utils::Query;
 use super::super::{Error, Result, Sort};
@@ -202,6 +204,13 @@ impl Port {
         set_extra_dhcp_opts, with_extra_dhcp_opts -> extra_dhcp_opts: Vec<protocol::PortExtraDhcpOption>
     }
 
+    /// A field returned by the Network API that has no dedicated accessor.
+    ///
+    /// For example, `binding:vif_type` or fields of other extensions.
+    pub fn extra_field(&self, key: &str) -> Option<&serde_json::Value> {
+        self.inner.extra.get(key)
+    }
+
     /// Fixed IP addresses of the port.
     pub fn fixed_ips(&self) -> &Vec<PortIpAddress> {
         &self.fixed_ips
@@ -297,6 +306,8 @@ impl Refresh for Port {
     }
 }
 
+taggable!(Port, "ports");
+
 impl PortIpAddress {
     /// Get subnet to which this IP address belongs.
     pub fn subnet(&self) -> Result<Subnet> {
@@ -345,6 +356,18 @@ impl PortQuery {
         set_admin_state_up, with_admin_state_up -> admin_state_up: bool
     }
 
+    /// Filter by the host the port is bound to.
+    pub fn set_binding_host_id<T: Into<String>>(&mut self, value: T) {
+        self.query.push_str("binding:host_id", value);
+    }
+
+    /// Filter by the host the port is bound to.
+    #[inline]
+    pub fn with_binding_host_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.set_binding_host_id(value);
+        self
+    }
+
     query_filter! {
         #[doc = "Filter by description."]
         set_description, with_description -> description
@@ -356,7 +379,7 @@ impl PortQuery {
     }
 
     query_filter! {
-        #[doc = "Filter by the ID of the object attached to the port."]
+        #[doc = "Filter by the owner of the port (e.g. `compute:nova` or `network:router_interface`)."]
         set_device_owner, with_device_owner -> device_owner
     }
 
@@ -381,6 +404,26 @@ impl PortQuery {
         self
     }
 
+    /// Filter by the server the port is attached to.
+    ///
+    /// The device owner is set to `compute:<availability zone>`, which is what
+    /// the Compute service uses for ports it creates (`compute:nova` by default).
+    #[cfg(feature = "compute")]
+    pub fn set_server(&mut self, server: &Server) {
+        self.set_device_id(server.id().clone());
+        let availability_zone = server.availability_zone();
+        if !availability_zone.is_empty() {
+            self.set_device_owner(format!("compute:{}", availability_zone));
+        }
+    }
+
+    /// Filter by the server the port is attached to.
+    #[cfg(feature = "compute")]
+    pub fn with_server(mut self, server: &Server) -> Self {
+        self.set_server(server);
+        self
+    }
+
     query_filter! {
         #[doc = "Filter by status."]
         set_status, with_status -> status: protocol::NetworkStatus
@@ -434,11 +477,23 @@ impl ResourceQuery for PortQuery {
     }
 
     fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
         let query = self.query.with_marker_and_limit(limit, marker);
-        Ok(api::list_ports(&self.session, &query)?
-            .into_iter()
-            .map(|item| Port::new(self.session.clone(), item))
-            .collect())
+        let (items, next_marker) = api::list_ports(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| Port::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
     }
 
     fn validate(&mut self) -> Result<()> {
@@ -476,6 +531,7 @@ impl NewPort {
                 // Dummy value, not used when serializing
                 status: protocol::NetworkStatus::Active,
                 updated_at: None,
+                extra: HashMap::new(),
             },
             network,
             fixed_ips: Vec::new(),
@@ -597,6 +653,12 @@ impl From<Port> for PortRef {
     }
 }
 
+impl<'a> From<&'a Port> for PortRef {
+    fn from(value: &'a Port) -> PortRef {
+        PortRef::new_verified(value.inner.id.clone())
+    }
+}
+
 #[cfg(feature = "network")]
 impl IntoVerified for PortRef {
     /// Verify this reference and convert to an ID, if possible.
@@ -17,13 +17,15 @@
 #![allow(non_snake_case)]
 #![allow(missing_docs)]
 
+use std::collections::HashMap;
+use std::fmt;
 use std::marker::PhantomData;
 use std::net;
 use std::ops::Not;
 
 use chrono::{DateTime, FixedOffset};
 use eui48::MacAddress;
-use osproto::common::empty_as_default;
+use osproto::common::{empty_as_default, Link};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -39,6 +41,14 @@ protocol_enum! {
     }
 }
 
+protocol_enum! {
+    #[doc = "Authentication type of a BGP peer."]
+    enum BgpAuthType {
+        Md5 = "md5",
+        None = "none"
+    }
+}
+
 protocol_enum! {
     #[doc = "Possible network statuses."]
     enum NetworkStatus {
@@ -145,10 +155,174 @@ protocol_enum! {
     }
 }
 
+protocol_enum! {
+    #[doc = "Possible statuses of VPN services and IPsec site connections."]
+    enum VpnStatus {
+        Active = "ACTIVE",
+        Building = "BUILD",
+        Down = "DOWN",
+        Error = "ERROR",
+        PendingCreate = "PENDING_CREATE",
+        PendingDelete = "PENDING_DELETE",
+        PendingUpdate = "PENDING_UPDATE"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Authentication algorithm for IKE and IPsec policies."]
+    enum VpnAuthAlgorithm {
+        AesCmac = "aes-cmac",
+        AesXcbc = "aes-xcbc",
+        Sha1 = "sha1",
+        Sha256 = "sha256",
+        Sha384 = "sha384",
+        Sha512 = "sha512"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Encryption algorithm for IKE and IPsec policies."]
+    enum VpnEncryptionAlgorithm {
+        TripleDes = "3des",
+        Aes128 = "aes-128",
+        Aes192 = "aes-192",
+        Aes256 = "aes-256",
+        Aes128Ctr = "aes-128-ctr",
+        Aes192Ctr = "aes-192-ctr",
+        Aes256Ctr = "aes-256-ctr",
+        Aes128Ccm8 = "aes-128-ccm-8",
+        Aes192Ccm8 = "aes-192-ccm-8",
+        Aes256Ccm8 = "aes-256-ccm-8",
+        Aes128Ccm12 = "aes-128-ccm-12",
+        Aes192Ccm12 = "aes-192-ccm-12",
+        Aes256Ccm12 = "aes-256-ccm-12",
+        Aes128Ccm16 = "aes-128-ccm-16",
+        Aes192Ccm16 = "aes-192-ccm-16",
+        Aes256Ccm16 = "aes-256-ccm-16",
+        Aes128Gcm8 = "aes-128-gcm-8",
+        Aes192Gcm8 = "aes-192-gcm-8",
+        Aes256Gcm8 = "aes-256-gcm-8",
+        Aes128Gcm12 = "aes-128-gcm-12",
+        Aes192Gcm12 = "aes-192-gcm-12",
+        Aes256Gcm12 = "aes-256-gcm-12",
+        Aes128Gcm16 = "aes-128-gcm-16",
+        Aes192Gcm16 = "aes-192-gcm-16",
+        Aes256Gcm16 = "aes-256-gcm-16"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Perfect forward secrecy (Diffie-Hellman) group."]
+    enum PfsGroup {
+        Group2 = "group2",
+        Group5 = "group5",
+        Group14 = "group14",
+        Group15 = "group15",
+        Group16 = "group16",
+        Group17 = "group17",
+        Group18 = "group18",
+        Group19 = "group19",
+        Group20 = "group20",
+        Group21 = "group21",
+        Group22 = "group22",
+        Group23 = "group23",
+        Group24 = "group24",
+        Group25 = "group25",
+        Group26 = "group26",
+        Group27 = "group27",
+        Group28 = "group28",
+        Group29 = "group29",
+        Group30 = "group30",
+        Group31 = "group31"
+    }
+}
+
+protocol_enum! {
+    #[doc = "IKE protocol version."]
+    enum IkeVersion {
+        V1 = "v1",
+        V2 = "v2"
+    }
+}
+
+protocol_enum! {
+    #[doc = "IKE phase 1 negotiation mode."]
+    enum Phase1NegotiationMode {
+        Aggressive = "aggressive",
+        Main = "main"
+    }
+}
+
+protocol_enum! {
+    #[doc = "IPsec encapsulation mode."]
+    enum IpsecEncapsulationMode {
+        Transport = "transport",
+        Tunnel = "tunnel"
+    }
+}
+
+protocol_enum! {
+    #[doc = "IPsec transform protocol."]
+    enum IpsecTransformProtocol {
+        Ah = "ah",
+        AhEsp = "ah-esp",
+        Esp = "esp"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Whether an IPsec site connection initiates connections."]
+    enum SiteConnectionInitiator {
+        BiDirectional = "bi-directional",
+        ResponseOnly = "response-only"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Action to take when a dead peer is detected."]
+    enum DeadPeerDetectionAction {
+        Clear = "clear",
+        Disabled = "disabled",
+        Hold = "hold",
+        Restart = "restart",
+        RestartByPeer = "restart-by-peer"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Ethernet type of a flow classifier."]
+    enum Ethertype {
+        Ipv4 = "IPv4",
+        Ipv6 = "IPv6"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Encapsulation used to correlate traffic in a service function chain."]
+    enum SfcCorrelation {
+        Mpls = "mpls",
+        Nsh = "nsh"
+    }
+}
+
+protocol_enum! {
+    #[doc = "Type of a provider network segment."]
+    enum NetworkType {
+        Flat = "flat",
+        Geneve = "geneve",
+        Gre = "gre",
+        Local = "local",
+        Vlan = "vlan",
+        Vxlan = "vxlan"
+    }
+}
+
 /// An network.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Network {
     pub admin_state_up: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub availability_zone_hints: Vec<String>,
     #[serde(default, skip_serializing)]
     pub availability_zones: Vec<String>,
     #[serde(default, skip_serializing)]
@@ -184,6 +358,8 @@ pub struct Network {
     pub port_security_enabled: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub project_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<ProviderSegment>,
     #[serde(default, skip_serializing_if = "Not::not")]
     pub shared: bool,
     #[serde(skip_serializing)]
@@ -194,12 +370,16 @@ pub struct Network {
     pub updated_at: Option<DateTime<FixedOffset>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vlan_transparent: Option<bool>,
+    // Fields not known to this crate.
+    #[serde(flatten, skip_serializing)]
+    pub extra: HashMap<String, Value>,
 }
 
 impl Default for Network {
     fn default() -> Network {
         Network {
             admin_state_up: true,
+            availability_zone_hints: Vec::new(),
             availability_zones: Vec::new(),
             created_at: None,
             description: None,
@@ -212,11 +392,13 @@ impl Default for Network {
             name: None,
             port_security_enabled: None,
             project_id: None,
+            segments: Vec::new(),
             shared: false,
             status: NetworkStatus::Active,
             subnets: Vec::new(),
             updated_at: None,
             vlan_transparent: None,
+            extra: HashMap::new(),
         }
     }
 }
@@ -260,6 +442,27 @@ pub struct NetworkUpdateRoot {
 #[derive(Debug, Clone, Deserialize)]
 pub struct NetworksRoot {
     pub networks: Vec<Network>,
+    #[serde(default, rename = "networks_links")]
+    pub links: Vec<Link>,
+}
+
+/// A provider segment of a network.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProviderSegment {
+    #[serde(rename = "provider:network_type")]
+    pub network_type: NetworkType,
+    #[serde(
+        default,
+        rename = "provider:physical_network",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub physical_network: Option<String>,
+    #[serde(
+        default,
+        rename = "provider:segmentation_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub segmentation_id: Option<u32>,
 }
 
 /// An extra DHCP option.
@@ -390,6 +593,9 @@ pub struct Port {
     pub status: NetworkStatus,
     #[serde(default, skip_serializing)]
     pub updated_at: Option<DateTime<FixedOffset>>,
+    // Fields not known to this crate.
+    #[serde(flatten, skip_serializing)]
+    pub extra: HashMap<String, Value>,
 }
 
 /// A port.
@@ -435,6 +641,8 @@ pub struct PortUpdateRoot {
 #[derive(Debug, Clone, Deserialize)]
 pub struct PortsRoot {
     pub ports: Vec<Port>,
+    #[serde(default, rename = "ports_links")]
+    pub links: Vec<Link>,
 }
 
 /// ConntrackHelper.
@@ -485,11 +693,17 @@ pub struct Routes {
     pub routes: Vec<HostRoute>,
 }
 
+/// A list of tags.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tags {
+    pub tags: Vec<String>,
+}
+
 /// A router.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Router {
     pub admin_state_up: bool,
-    #[serde(default, skip_serializing)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub availability_zone_hints: Vec<String>,
     #[serde(default, skip_serializing)]
     pub availability_zones: Vec<String>,
@@ -615,6 +829,8 @@ pub struct RouterUpdateRoot {
 #[derive(Debug, Clone, Deserialize)]
 pub struct RoutersRoot {
     pub routers: Vec<Router>,
+    #[serde(default, rename = "routers_links")]
+    pub links: Vec<Link>,
 }
 
 /// An allocation pool.
@@ -677,6 +893,12 @@ pub struct Subnet {
     pub network_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub project_id: Option<String>,
+    #[serde(
+        default,
+        rename = "subnetpool_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subnet_pool_id: Option<String>,
     #[serde(default, skip_serializing)]
     pub updated_at: Option<DateTime<FixedOffset>>,
 }
@@ -702,6 +924,7 @@ impl Subnet {
             name: None,
             network_id: String::new(),
             project_id: None,
+            subnet_pool_id: None,
             updated_at: None,
         }
     }
@@ -742,6 +965,8 @@ pub struct SubnetUpdateRoot {
 #[derive(Debug, Clone, Deserialize)]
 pub struct SubnetsRoot {
     pub subnets: Vec<Subnet>,
+    #[serde(default, rename = "subnets_links")]
+    pub links: Vec<Link>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
@@ -827,4 +1052,990 @@ pub struct FloatingIpUpdateRoot {
 #[derive(Debug, Clone, Deserialize)]
 pub struct FloatingIpsRoot {
     pub floatingips: Vec<FloatingIp>,
+    #[serde(default, rename = "floatingips_links")]
+    pub links: Vec<Link>,
+}
+
+/// Lifetime of a security association.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct VpnLifetime {
+    /// Lifetime units (only "seconds" is supported by Neutron).
+    pub units: String,
+    /// Lifetime value.
+    pub value: u32,
+}
+
+impl VpnLifetime {
+    /// Create a lifetime in seconds.
+    pub fn seconds(value: u32) -> VpnLifetime {
+        VpnLifetime {
+            units: String::from("seconds"),
+            value,
+        }
+    }
+}
+
+/// Dead peer detection settings.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DeadPeerDetection {
+    /// Action to take.
+    pub action: DeadPeerDetectionAction,
+    /// Interval between checks (in seconds).
+    pub interval: u32,
+    /// Timeout after which the peer is considered dead (in seconds).
+    pub timeout: u32,
+}
+
+/// A VPN service.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VpnService {
+    pub admin_state_up: bool,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(deserialize_with = "empty_as_default", default, skip_serializing)]
+    pub external_v4_ip: Option<net::Ipv4Addr>,
+    #[serde(deserialize_with = "empty_as_default", default, skip_serializing)]
+    pub external_v6_ip: Option<net::Ipv6Addr>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub flavor_id: Option<String>,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    pub router_id: String,
+    #[serde(skip_serializing)]
+    pub status: VpnStatus,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subnet_id: Option<String>,
+}
+
+/// A VPN service.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct VpnServiceUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_state_up: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A VPN service.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VpnServiceRoot {
+    pub vpnservice: VpnService,
+}
+
+/// A VPN service.
+#[derive(Debug, Clone, Serialize)]
+pub struct VpnServiceUpdateRoot {
+    pub vpnservice: VpnServiceUpdate,
+}
+
+/// A list of VPN services.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VpnServicesRoot {
+    pub vpnservices: Vec<VpnService>,
+    #[serde(default, rename = "vpnservices_links")]
+    pub links: Vec<Link>,
+}
+
+/// An IKE policy.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct IkePolicy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_algorithm: Option<VpnAuthAlgorithm>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_algorithm: Option<VpnEncryptionAlgorithm>,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ike_version: Option<IkeVersion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lifetime: Option<VpnLifetime>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pfs: Option<PfsGroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase1_negotiation_mode: Option<Phase1NegotiationMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// An IKE policy.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IkePolicyUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_algorithm: Option<VpnAuthAlgorithm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_algorithm: Option<VpnEncryptionAlgorithm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ike_version: Option<IkeVersion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifetime: Option<VpnLifetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pfs: Option<PfsGroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase1_negotiation_mode: Option<Phase1NegotiationMode>,
+}
+
+/// An IKE policy.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IkePolicyRoot {
+    pub ikepolicy: IkePolicy,
+}
+
+/// An IKE policy.
+#[derive(Debug, Clone, Serialize)]
+pub struct IkePolicyUpdateRoot {
+    pub ikepolicy: IkePolicyUpdate,
+}
+
+/// A list of IKE policies.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IkePoliciesRoot {
+    pub ikepolicies: Vec<IkePolicy>,
+    #[serde(default, rename = "ikepolicies_links")]
+    pub links: Vec<Link>,
+}
+
+/// An IPsec policy.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct IpsecPolicy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_algorithm: Option<VpnAuthAlgorithm>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encapsulation_mode: Option<IpsecEncapsulationMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_algorithm: Option<VpnEncryptionAlgorithm>,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lifetime: Option<VpnLifetime>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pfs: Option<PfsGroup>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform_protocol: Option<IpsecTransformProtocol>,
+}
+
+/// An IPsec policy.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IpsecPolicyUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_algorithm: Option<VpnAuthAlgorithm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encapsulation_mode: Option<IpsecEncapsulationMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_algorithm: Option<VpnEncryptionAlgorithm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifetime: Option<VpnLifetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pfs: Option<PfsGroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform_protocol: Option<IpsecTransformProtocol>,
+}
+
+/// An IPsec policy.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IpsecPolicyRoot {
+    pub ipsecpolicy: IpsecPolicy,
+}
+
+/// An IPsec policy.
+#[derive(Debug, Clone, Serialize)]
+pub struct IpsecPolicyUpdateRoot {
+    pub ipsecpolicy: IpsecPolicyUpdate,
+}
+
+/// A list of IPsec policies.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IpsecPoliciesRoot {
+    pub ipsecpolicies: Vec<IpsecPolicy>,
+    #[serde(default, rename = "ipsecpolicies_links")]
+    pub links: Vec<Link>,
+}
+
+/// An IPsec site connection.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct SiteConnection {
+    pub admin_state_up: bool,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dpd: Option<DeadPeerDetection>,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(rename = "ikepolicy_id")]
+    pub ike_policy_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiator: Option<SiteConnectionInitiator>,
+    #[serde(rename = "ipsecpolicy_id")]
+    pub ipsec_policy_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u32>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<String>,
+    pub peer_address: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub peer_cidrs: Vec<String>,
+    pub peer_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub psk: String,
+    #[serde(skip_serializing)]
+    pub status: VpnStatus,
+    #[serde(rename = "vpnservice_id")]
+    pub vpn_service_id: String,
+}
+
+/// An IPsec site connection.
+#[derive(Clone, Default, Serialize)]
+pub struct SiteConnectionUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_state_up: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dpd: Option<DeadPeerDetection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiator: Option<SiteConnectionInitiator>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_cidrs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub psk: Option<String>,
+}
+
+// Secrets, like pre-shared keys and passwords, must not end up in the logs.
+const REDACTED: &str = "***";
+
+impl fmt::Debug for SiteConnection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SiteConnection")
+            .field("admin_state_up", &self.admin_state_up)
+            .field("description", &self.description)
+            .field("dpd", &self.dpd)
+            .field("id", &self.id)
+            .field("ike_policy_id", &self.ike_policy_id)
+            .field("initiator", &self.initiator)
+            .field("ipsec_policy_id", &self.ipsec_policy_id)
+            .field("mtu", &self.mtu)
+            .field("name", &self.name)
+            .field("peer_address", &self.peer_address)
+            .field("peer_cidrs", &self.peer_cidrs)
+            .field("peer_id", &self.peer_id)
+            .field("project_id", &self.project_id)
+            .field("psk", &REDACTED)
+            .field("status", &self.status)
+            .field("vpn_service_id", &self.vpn_service_id)
+            .finish()
+    }
+}
+
+impl fmt::Debug for SiteConnectionUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SiteConnectionUpdate")
+            .field("admin_state_up", &self.admin_state_up)
+            .field("description", &self.description)
+            .field("dpd", &self.dpd)
+            .field("initiator", &self.initiator)
+            .field("mtu", &self.mtu)
+            .field("name", &self.name)
+            .field("peer_address", &self.peer_address)
+            .field("peer_cidrs", &self.peer_cidrs)
+            .field("peer_id", &self.peer_id)
+            .field("psk", &self.psk.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
+
+/// An IPsec site connection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SiteConnectionRoot {
+    pub ipsec_site_connection: SiteConnection,
+}
+
+/// An IPsec site connection.
+#[derive(Debug, Clone, Serialize)]
+pub struct SiteConnectionUpdateRoot {
+    pub ipsec_site_connection: SiteConnectionUpdate,
+}
+
+/// A list of IPsec site connections.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SiteConnectionsRoot {
+    pub ipsec_site_connections: Vec<SiteConnection>,
+    #[serde(default, rename = "ipsec_site_connections_links")]
+    pub links: Vec<Link>,
+}
+
+/// A network agent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Agent {
+    pub admin_state_up: bool,
+    pub agent_type: String,
+    pub alive: bool,
+    #[serde(deserialize_with = "empty_as_default", default)]
+    pub availability_zone: Option<String>,
+    pub binary: String,
+    #[serde(default)]
+    pub configurations: HashMap<String, Value>,
+    #[serde(deserialize_with = "empty_as_default", default)]
+    pub description: Option<String>,
+    pub host: String,
+    pub id: String,
+    pub topic: String,
+}
+
+/// A network agent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AgentRoot {
+    pub agent: Agent,
+}
+
+/// A list of network agents.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AgentsRoot {
+    pub agents: Vec<Agent>,
+    #[serde(default, rename = "agents_links")]
+    pub links: Vec<Link>,
+}
+
+/// A subnet pool.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SubnetPool {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_scope_id: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub created_at: Option<DateTime<FixedOffset>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_prefixlen: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_quota: Option<u32>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(skip_serializing)]
+    pub ip_version: IpVersion,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub is_default: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_prefixlen: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_prefixlen: Option<u8>,
+    pub name: String,
+    pub prefixes: Vec<ipnet::IpNet>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub shared: bool,
+    #[serde(default, skip_serializing)]
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+/// A subnet pool.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SubnetPoolUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_scope_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_prefixlen: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_quota: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_default: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_prefixlen: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_prefixlen: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefixes: Option<Vec<ipnet::IpNet>>,
+}
+
+/// A subnet pool.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SubnetPoolRoot {
+    pub subnetpool: SubnetPool,
+}
+
+/// A subnet pool.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubnetPoolUpdateRoot {
+    pub subnetpool: SubnetPoolUpdate,
+}
+
+/// A list of subnet pools.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubnetPoolsRoot {
+    pub subnetpools: Vec<SubnetPool>,
+    #[serde(default, rename = "subnetpools_links")]
+    pub links: Vec<Link>,
+}
+
+/// An address scope.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AddressScope {
+    #[serde(skip_serializing)]
+    pub id: String,
+    pub ip_version: IpVersion,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub shared: bool,
+}
+
+/// An address scope.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AddressScopeUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+}
+
+/// An address scope.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AddressScopeRoot {
+    pub address_scope: AddressScope,
+}
+
+/// An address scope.
+#[derive(Debug, Clone, Serialize)]
+pub struct AddressScopeUpdateRoot {
+    pub address_scope: AddressScopeUpdate,
+}
+
+/// A list of address scopes.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AddressScopesRoot {
+    pub address_scopes: Vec<AddressScope>,
+    #[serde(default, rename = "address_scopes_links")]
+    pub links: Vec<Link>,
+}
+
+/// A network segment.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Segment {
+    #[serde(deserialize_with = "empty_as_default", default)]
+    pub description: Option<String>,
+    pub id: String,
+    #[serde(deserialize_with = "empty_as_default", default)]
+    pub name: Option<String>,
+    pub network_id: String,
+    pub network_type: NetworkType,
+    #[serde(default)]
+    pub physical_network: Option<String>,
+    #[serde(default)]
+    pub segmentation_id: Option<u32>,
+}
+
+/// A network segment.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SegmentRoot {
+    pub segment: Segment,
+}
+
+/// A list of network segments.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SegmentsRoot {
+    pub segments: Vec<Segment>,
+    #[serde(default, rename = "segments_links")]
+    pub links: Vec<Link>,
+}
+
+/// Parameters of a service function in a port pair.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ServiceFunctionParameters {
+    /// Correlation supported by the service function (if any).
+    #[serde(default)]
+    pub correlation: Option<SfcCorrelation>,
+    /// Weight of the port pair when load balancing inside a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u32>,
+}
+
+/// Parameters of a port chain.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ChainParameters {
+    /// Correlation used in the chain (if any).
+    #[serde(default)]
+    pub correlation: Option<SfcCorrelation>,
+    /// Whether the chain also applies to reverse traffic.
+    #[serde(default)]
+    pub symmetric: bool,
+}
+
+/// A port pair.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PortPair {
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(rename = "egress")]
+    pub egress_port_id: String,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(rename = "ingress")]
+    pub ingress_port_id: String,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_function_parameters: Option<ServiceFunctionParameters>,
+}
+
+/// A port pair.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PortPairUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A port pair.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PortPairRoot {
+    pub port_pair: PortPair,
+}
+
+/// A port pair.
+#[derive(Debug, Clone, Serialize)]
+pub struct PortPairUpdateRoot {
+    pub port_pair: PortPairUpdate,
+}
+
+/// A list of port pairs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PortPairsRoot {
+    pub port_pairs: Vec<PortPair>,
+    #[serde(default, rename = "port_pairs_links")]
+    pub links: Vec<Link>,
+}
+
+/// A port pair group.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PortPairGroup {
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<String>,
+    pub port_pairs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// A port pair group.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PortPairGroupUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_pairs: Option<Vec<String>>,
+}
+
+/// A port pair group.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PortPairGroupRoot {
+    pub port_pair_group: PortPairGroup,
+}
+
+/// A port pair group.
+#[derive(Debug, Clone, Serialize)]
+pub struct PortPairGroupUpdateRoot {
+    pub port_pair_group: PortPairGroupUpdate,
+}
+
+/// A list of port pair groups.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PortPairGroupsRoot {
+    pub port_pair_groups: Vec<PortPairGroup>,
+    #[serde(default, rename = "port_pair_groups_links")]
+    pub links: Vec<Link>,
+}
+
+/// A flow classifier.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FlowClassifier {
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_ip_prefix: Option<ipnet::IpNet>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_port_range_max: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_port_range_min: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ethertype: Option<Ethertype>,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logical_destination_port: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logical_source_port: Option<String>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_ip_prefix: Option<ipnet::IpNet>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_port_range_max: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_port_range_min: Option<u16>,
+}
+
+/// A flow classifier.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FlowClassifierUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A flow classifier.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FlowClassifierRoot {
+    pub flow_classifier: FlowClassifier,
+}
+
+/// A flow classifier.
+#[derive(Debug, Clone, Serialize)]
+pub struct FlowClassifierUpdateRoot {
+    pub flow_classifier: FlowClassifierUpdate,
+}
+
+/// A list of flow classifiers.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FlowClassifiersRoot {
+    pub flow_classifiers: Vec<FlowClassifier>,
+    #[serde(default, rename = "flow_classifiers_links")]
+    pub links: Vec<Link>,
+}
+
+/// A port chain.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PortChain {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_parameters: Option<ChainParameters>,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flow_classifiers: Vec<String>,
+    #[serde(skip_serializing)]
+    pub id: String,
+    #[serde(
+        deserialize_with = "empty_as_default",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<String>,
+    pub port_pair_groups: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// A port chain.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PortChainUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow_classifiers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_pair_groups: Option<Vec<String>>,
+}
+
+/// A port chain.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PortChainRoot {
+    pub port_chain: PortChain,
+}
+
+/// A port chain.
+#[derive(Debug, Clone, Serialize)]
+pub struct PortChainUpdateRoot {
+    pub port_chain: PortChainUpdate,
+}
+
+/// A list of port chains.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PortChainsRoot {
+    pub port_chains: Vec<PortChain>,
+    #[serde(default, rename = "port_chains_links")]
+    pub links: Vec<Link>,
+}
+
+/// A BGP speaker.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BgpSpeaker {
+    pub advertise_floating_ip_host_routes: bool,
+    pub advertise_tenant_networks: bool,
+    #[serde(skip_serializing)]
+    pub id: String,
+    pub ip_version: IpVersion,
+    pub local_as: u32,
+    pub name: String,
+    #[serde(default, skip_serializing)]
+    pub networks: Vec<String>,
+    #[serde(default, skip_serializing)]
+    pub peers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// A BGP speaker.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BgpSpeakerUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertise_floating_ip_host_routes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertise_tenant_networks: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A BGP speaker.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BgpSpeakerRoot {
+    pub bgp_speaker: BgpSpeaker,
+}
+
+/// A BGP speaker.
+#[derive(Debug, Clone, Serialize)]
+pub struct BgpSpeakerUpdateRoot {
+    pub bgp_speaker: BgpSpeakerUpdate,
+}
+
+/// A list of BGP speakers.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BgpSpeakersRoot {
+    pub bgp_speakers: Vec<BgpSpeaker>,
+    #[serde(default, rename = "bgp_speakers_links")]
+    pub links: Vec<Link>,
+}
+
+/// A route advertised by a BGP speaker.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BgpRoute {
+    /// Destination network.
+    pub destination: ipnet::IpNet,
+    /// Next hop address.
+    pub next_hop: net::IpAddr,
+}
+
+/// A list of routes advertised by a BGP speaker.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BgpRoutesRoot {
+    pub advertised_routes: Vec<BgpRoute>,
+}
+
+/// A BGP peer.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct BgpPeer {
+    pub auth_type: BgpAuthType,
+    #[serde(skip_serializing)]
+    pub id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    pub peer_ip: net::IpAddr,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    pub remote_as: u32,
+}
+
+/// A BGP peer.
+#[derive(Clone, Default, Serialize)]
+pub struct BgpPeerUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+impl fmt::Debug for BgpPeer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BgpPeer")
+            .field("auth_type", &self.auth_type)
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .field("peer_ip", &self.peer_ip)
+            .field("project_id", &self.project_id)
+            .field("remote_as", &self.remote_as)
+            .finish()
+    }
+}
+
+impl fmt::Debug for BgpPeerUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BgpPeerUpdate")
+            .field("name", &self.name)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
+
+/// A BGP peer.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BgpPeerRoot {
+    pub bgp_peer: BgpPeer,
+}
+
+/// A BGP peer.
+#[derive(Debug, Clone, Serialize)]
+pub struct BgpPeerUpdateRoot {
+    pub bgp_peer: BgpPeerUpdate,
+}
+
+/// A list of BGP peers.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BgpPeersRoot {
+    pub bgp_peers: Vec<BgpPeer>,
+    #[serde(default, rename = "bgp_peers_links")]
+    pub links: Vec<Link>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BgpAuthType, BgpPeer, BgpPeerUpdate};
+
+    #[test]
+    fn test_bgp_peer_debug_redacts_password() {
+        let peer = BgpPeer {
+            auth_type: BgpAuthType::Md5,
+            id: "1234".into(),
+            name: "peer".into(),
+            password: Some("s3cr3t".into()),
+            peer_ip: "192.0.2.1".parse().unwrap(),
+            project_id: None,
+            remote_as: 64512,
+        };
+        let output = format!("{:?}", peer);
+        assert!(!output.contains("s3cr3t"));
+        assert!(output.contains("***"));
+
+        let update = BgpPeerUpdate {
+            name: None,
+            password: Some("s3cr3t".into()),
+        };
+        assert!(!format!("{:?}", update).contains("s3cr3t"));
+    }
 }
@@ -20,12 +20,12 @@ use chrono::{DateTime, FixedOffset};
 use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
 
 use super::super::common::{
-    DeletionWaiter, IntoVerified, Refresh, ResourceIterator, ResourceQuery, RouterRef,
+    DeletionWaiter, IntoVerified, NetworkRef, Refresh, ResourceIterator, ResourceQuery, RouterRef,
 };
 use super::super::session::Session;
 use super::super::utils::Query;
 use super::super::{Error, ErrorKind, Result, Sort};
-use super::{api, protocol, Network};
+use super::{api, protocol, Network, NetworkAgent};
 
 /// A query to router list.
 #[derive(Clone, Debug)]
@@ -33,6 +33,7 @@ pub struct RouterQuery {
     session: Rc<Session>,
     query: Query,
     can_paginate: bool,
+    external_gateway_network: Option<NetworkRef>,
 }
 
 /// Structure representing a single router.
@@ -52,7 +53,7 @@ pub struct NewRouter {
 
 impl Router {
     /// Create a router object.
-    fn new(session: Rc<Session>, inner: protocol::Router) -> Router {
+    pub(crate) fn new(session: Rc<Session>, inner: protocol::Router) -> Router {
         Router {
             session,
             inner,
@@ -243,6 +244,24 @@ impl Router {
         Ok(())
     }
 
+    /// Schedule the router to an L3 agent (requires administrator rights).
+    pub fn add_l3_agent<S: AsRef<str>>(&self, agent_id: S) -> Result<()> {
+        api::add_router_to_l3_agent(&self.session, agent_id, &self.inner.id)
+    }
+
+    /// List L3 agents hosting the router (requires administrator rights).
+    pub fn l3_agents(&self) -> Result<Vec<NetworkAgent>> {
+        Ok(api::list_router_l3_agents(&self.session, &self.inner.id)?
+            .into_iter()
+            .map(|item| NetworkAgent::new(self.session.clone(), item))
+            .collect())
+    }
+
+    /// Remove the router from an L3 agent (requires administrator rights).
+    pub fn remove_l3_agent<S: AsRef<str>>(&self, agent_id: S) -> Result<()> {
+        api::remove_router_from_l3_agent(&self.session, agent_id, &self.inner.id)
+    }
+
     /// Add an interface to the router.
     pub fn add_router_interface(
         &mut self,
@@ -281,12 +300,15 @@ impl Refresh for Router {
     }
 }
 
+taggable!(Router, "routers", cached tags);
+
 impl RouterQuery {
     pub(crate) fn new(session: Rc<Session>) -> RouterQuery {
         RouterQuery {
             session,
             query: Query::new(),
             can_paginate: true,
+            external_gateway_network: None,
         }
     }
 
@@ -316,12 +338,36 @@ impl RouterQuery {
         self
     }
 
+    query_filter! {
+        #[doc = "Filter by administrative state."]
+        set_admin_state_up, with_admin_state_up -> admin_state_up: bool
+    }
+
+    /// Filter by the network of the external gateway.
+    ///
+    /// Routers are filtered after fetching, so every page of routers has to
+    /// be fetched.
+    pub fn set_external_gateway_network<N: Into<NetworkRef>>(&mut self, value: N) {
+        self.external_gateway_network = Some(value.into());
+    }
+
+    /// Filter by the network of the external gateway.
+    pub fn with_external_gateway_network<N: Into<NetworkRef>>(mut self, value: N) -> Self {
+        self.set_external_gateway_network(value);
+        self
+    }
+
     /// Filter by router name (a database regular expression).
     pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
         self.query.push_str("name", value);
         self
     }
 
+    query_filter! {
+        #[doc = "Filter by router status."]
+        set_status, with_status -> status: protocol::RouterStatus
+    }
+
     /// Convert this query into an iterator executing the request.
     ///
     /// Returns a `FallibleIterator`, which is an iterator with each `next`
@@ -370,11 +416,37 @@ impl ResourceQuery for RouterQuery {
     }
 
     fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
         let query = self.query.with_marker_and_limit(limit, marker);
-        Ok(api::list_routers(&self.session, &query)?
-            .into_iter()
-            .map(|item| Router::new(self.session.clone(), item))
-            .collect())
+        let (mut items, next_marker) = api::list_routers(&self.session, &query)?;
+        if let Some(ref network) = self.external_gateway_network {
+            items.retain(|item| match item.external_gateway {
+                Some(ref gateway) => gateway.network_id.as_ref() == network.as_ref(),
+                None => false,
+            });
+        }
+
+        Ok((
+            items
+                .into_iter()
+                .map(|item| Router::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+
+    fn validate(&mut self) -> Result<()> {
+        if let Some(network) = self.external_gateway_network.take() {
+            self.external_gateway_network = Some(network.into_verified(&self.session)?);
+        }
+        Ok(())
     }
 }
 
@@ -398,6 +470,11 @@ impl NewRouter {
         set_admin_state_up, with_admin_state_up -> admin_state_up: bool
     }
 
+    creation_inner_vec! {
+        #[doc = "Availability zone candidate(s) for the router."]
+        add_availability_zone_hint, with_availability_zone_hint -> availability_zone_hints
+    }
+
     creation_inner_field! {
         #[doc = "Set the availability zone candidates for the router."]
         set_availability_zone_hints, with_availability_zone_hints -> availability_zone_hints: Vec<String>
@@ -462,6 +539,12 @@ impl From<Router> for RouterRef {
     }
 }
 
+impl<'a> From<&'a Router> for RouterRef {
+    fn from(value: &'a Router) -> RouterRef {
+        RouterRef::new_verified(value.inner.id.clone())
+    }
+}
+
 #[cfg(feature = "network")]
 impl IntoVerified for RouterRef {
     /// Verify this reference and convert to an ID, if possible.
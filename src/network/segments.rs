@@ -0,0 +1,274 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Network segment management via Network API.
+
+use std::rc::Rc;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{IntoVerified, NetworkRef, Refresh, ResourceIterator, ResourceQuery};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol, Network};
+
+/// A query to network segment list.
+#[derive(Clone, Debug)]
+pub struct NetworkSegmentQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+    network: Option<NetworkRef>,
+}
+
+/// Structure representing a single network segment.
+#[derive(Clone, Debug)]
+pub struct NetworkSegment {
+    session: Rc<Session>,
+    inner: protocol::Segment,
+}
+
+/// A provider segment to create a network with.
+#[derive(Clone, Debug)]
+pub struct NewNetworkSegment {
+    pub(crate) inner: protocol::ProviderSegment,
+}
+
+impl NetworkSegment {
+    /// Create a network segment object.
+    fn new(session: Rc<Session>, inner: protocol::Segment) -> NetworkSegment {
+        NetworkSegment { session, inner }
+    }
+
+    /// Load a NetworkSegment object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<NetworkSegment> {
+        let inner = api::get_segment(&session, id)?;
+        Ok(NetworkSegment::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "Network segment description."]
+        description: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Network segment name."]
+        name: ref Option<String>
+    }
+
+    /// Get the network this segment belongs to.
+    pub fn network(&self) -> Result<Network> {
+        Network::load(self.session.clone(), &self.inner.network_id)
+    }
+
+    transparent_property! {
+        #[doc = "ID of the network this segment belongs to."]
+        network_id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Type of the segment."]
+        network_type: protocol::NetworkType
+    }
+
+    transparent_property! {
+        #[doc = "Name of the physical network (if applicable)."]
+        physical_network: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "VLAN ID or tunnel ID of the segment (if applicable)."]
+        segmentation_id: Option<u32>
+    }
+}
+
+impl Refresh for NetworkSegment {
+    /// Refresh the network segment.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_segment_by_id(&self.session, &self.inner.id)?;
+        Ok(())
+    }
+}
+
+impl NetworkSegmentQuery {
+    pub(crate) fn new(session: Rc<Session>) -> NetworkSegmentQuery {
+        NetworkSegmentQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+            network: None,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by network segment name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Filter by network.
+    pub fn set_network<N: Into<NetworkRef>>(&mut self, value: N) {
+        self.network = Some(value.into());
+    }
+
+    /// Filter by network.
+    pub fn with_network<N: Into<NetworkRef>>(mut self, value: N) -> Self {
+        self.set_network(value);
+        self
+    }
+
+    query_filter! {
+        #[doc = "Filter by segment type."]
+        set_network_type, with_network_type -> network_type: protocol::NetworkType
+    }
+
+    query_filter! {
+        #[doc = "Filter by physical network."]
+        set_physical_network, with_physical_network -> physical_network
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<NetworkSegmentQuery> {
+        debug!("Fetching network segments with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<NetworkSegment>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<NetworkSegment> {
+        debug!("Fetching one network segment with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for NetworkSegmentQuery {
+    type Item = NetworkSegment;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_segments(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| NetworkSegment::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+
+    fn validate(&mut self) -> Result<()> {
+        if let Some(network) = self.network.take() {
+            let verified = network.into_verified(&self.session)?;
+            self.query.push_str("network_id", verified);
+        }
+        Ok(())
+    }
+}
+
+impl NewNetworkSegment {
+    /// Start describing a provider segment of the given type.
+    pub fn new(network_type: protocol::NetworkType) -> NewNetworkSegment {
+        NewNetworkSegment {
+            inner: protocol::ProviderSegment {
+                network_type,
+                physical_network: None,
+                segmentation_id: None,
+            },
+        }
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the name of the physical network."]
+        set_physical_network, with_physical_network -> physical_network: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the VLAN ID or tunnel ID of the segment."]
+        set_segmentation_id, with_segmentation_id -> segmentation_id: optional u32
+    }
+}
+
+impl IntoFallibleIterator for NetworkSegmentQuery {
+    type Item = NetworkSegment;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<NetworkSegmentQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
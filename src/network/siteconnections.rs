@@ -0,0 +1,487 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! IPsec site connection management via Network API.
+
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    DeletionWaiter, IkePolicyRef, IntoVerified, IpsecPolicyRef, Refresh, ResourceIterator,
+    ResourceQuery, VpnServiceRef,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol, IkePolicy, IpsecPolicy, VpnService};
+
+/// A query to IPsec site connection list.
+#[derive(Clone, Debug)]
+pub struct SiteConnectionQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+    vpn_service: Option<VpnServiceRef>,
+}
+
+/// Structure representing a single IPsec site connection.
+#[derive(Clone, Debug)]
+pub struct SiteConnection {
+    session: Rc<Session>,
+    inner: protocol::SiteConnection,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create an IPsec site connection.
+#[derive(Clone, Debug)]
+pub struct NewSiteConnection {
+    session: Rc<Session>,
+    inner: protocol::SiteConnection,
+    vpn_service: VpnServiceRef,
+    ike_policy: IkePolicyRef,
+    ipsec_policy: IpsecPolicyRef,
+}
+
+impl SiteConnection {
+    /// Create an IPsec site connection object.
+    fn new(session: Rc<Session>, inner: protocol::SiteConnection) -> SiteConnection {
+        SiteConnection {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a SiteConnection object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<SiteConnection> {
+        let inner = api::get_site_connection(&session, id)?;
+        Ok(SiteConnection::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "The administrative state of the site connection."]
+        admin_state_up: bool
+    }
+
+    update_field! {
+        #[doc = "Set the administrative state of the site connection."]
+        set_admin_state_up, with_admin_state_up -> admin_state_up: bool
+    }
+
+    transparent_property! {
+        #[doc = "Site connection description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Dead peer detection settings."]
+        dpd: Option<protocol::DeadPeerDetection>
+    }
+
+    update_field! {
+        #[doc = "Update the dead peer detection settings."]
+        set_dpd, with_dpd -> dpd: optional protocol::DeadPeerDetection
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    /// Get the IKE policy used by this site connection.
+    pub fn ike_policy(&self) -> Result<IkePolicy> {
+        IkePolicy::load(self.session.clone(), &self.inner.ike_policy_id)
+    }
+
+    transparent_property! {
+        #[doc = "ID of the IKE policy used by this site connection."]
+        ike_policy_id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Whether this site connection initiates connections."]
+        initiator: Option<protocol::SiteConnectionInitiator>
+    }
+
+    update_field! {
+        #[doc = "Update whether this site connection initiates connections."]
+        set_initiator, with_initiator -> initiator: optional protocol::SiteConnectionInitiator
+    }
+
+    /// Get the IPsec policy used by this site connection.
+    pub fn ipsec_policy(&self) -> Result<IpsecPolicy> {
+        IpsecPolicy::load(self.session.clone(), &self.inner.ipsec_policy_id)
+    }
+
+    transparent_property! {
+        #[doc = "ID of the IPsec policy used by this site connection."]
+        ipsec_policy_id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Maximum transmission unit (if set)."]
+        mtu: Option<u32>
+    }
+
+    update_field! {
+        #[doc = "Update the maximum transmission unit."]
+        set_mtu, with_mtu -> mtu: optional u32
+    }
+
+    transparent_property! {
+        #[doc = "Site connection name."]
+        name: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Address (IP or FQDN) of the peer gateway."]
+        peer_address: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the address of the peer gateway."]
+        set_peer_address, with_peer_address -> peer_address: String
+    }
+
+    transparent_property! {
+        #[doc = "CIDRs of the remote subnets."]
+        peer_cidrs: ref Vec<String>
+    }
+
+    update_field! {
+        #[doc = "Update the CIDRs of the remote subnets."]
+        set_peer_cidrs, with_peer_cidrs -> peer_cidrs: Vec<String>
+    }
+
+    transparent_property! {
+        #[doc = "Router identity of the peer."]
+        peer_id: ref String
+    }
+
+    update_field! {
+        #[doc = "Update the router identity of the peer."]
+        set_peer_id, with_peer_id -> peer_id: String
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the pre-shared key."]
+        set_psk, with_psk -> psk: String
+    }
+
+    transparent_property! {
+        #[doc = "Status of the site connection."]
+        status: protocol::VpnStatus
+    }
+
+    /// Get the VPN service this site connection belongs to.
+    pub fn vpn_service(&self) -> Result<VpnService> {
+        VpnService::load(self.session.clone(), &self.inner.vpn_service_id)
+    }
+
+    transparent_property! {
+        #[doc = "ID of the VPN service this site connection belongs to."]
+        vpn_service_id: ref String
+    }
+
+    /// Delete the site connection.
+    pub fn delete(self) -> Result<DeletionWaiter<SiteConnection>> {
+        api::delete_site_connection(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the site connection is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the site connection.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::SiteConnectionUpdate::default();
+        save_fields! {
+            self -> update: admin_state_up peer_address peer_cidrs peer_id psk
+        };
+        save_option_fields! {
+            self -> update: description dpd initiator mtu name
+        };
+        let inner = api::update_site_connection(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for SiteConnection {
+    /// Refresh the site connection.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_site_connection_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl SiteConnectionQuery {
+    pub(crate) fn new(session: Rc<Session>) -> SiteConnectionQuery {
+        SiteConnectionQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+            vpn_service: None,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by site connection name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    query_filter! {
+        #[doc = "Filter by site connection status."]
+        set_status, with_status -> status: protocol::VpnStatus
+    }
+
+    /// Filter by VPN service.
+    pub fn set_vpn_service<V: Into<VpnServiceRef>>(&mut self, value: V) {
+        self.vpn_service = Some(value.into());
+    }
+
+    /// Filter by VPN service.
+    pub fn with_vpn_service<V: Into<VpnServiceRef>>(mut self, value: V) -> Self {
+        self.set_vpn_service(value);
+        self
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<SiteConnectionQuery> {
+        debug!("Fetching IPsec site connections with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<SiteConnection>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<SiteConnection> {
+        debug!("Fetching one IPsec site connection with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for SiteConnectionQuery {
+    type Item = SiteConnection;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_site_connections(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| SiteConnection::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+
+    fn validate(&mut self) -> Result<()> {
+        if let Some(vpn_service) = self.vpn_service.take() {
+            let verified = vpn_service.into_verified(&self.session)?;
+            self.query.push_str("vpnservice_id", verified);
+        }
+        Ok(())
+    }
+}
+
+impl NewSiteConnection {
+    /// Start creating an IPsec site connection.
+    pub(crate) fn new(
+        session: Rc<Session>,
+        vpn_service: VpnServiceRef,
+        ike_policy: IkePolicyRef,
+        ipsec_policy: IpsecPolicyRef,
+        peer_address: String,
+        psk: String,
+    ) -> NewSiteConnection {
+        NewSiteConnection {
+            session,
+            inner: protocol::SiteConnection {
+                admin_state_up: true,
+                description: None,
+                dpd: None,
+                id: String::new(),
+                // Will be replaced in create()
+                ike_policy_id: String::new(),
+                initiator: None,
+                // Will be replaced in create()
+                ipsec_policy_id: String::new(),
+                mtu: None,
+                name: None,
+                // The peer is usually identified by its address.
+                peer_id: peer_address.clone(),
+                peer_address,
+                peer_cidrs: Vec::new(),
+                project_id: None,
+                psk,
+                // Dummy value, not used when serializing
+                status: protocol::VpnStatus::Active,
+                // Will be replaced in create()
+                vpn_service_id: String::new(),
+            },
+            vpn_service,
+            ike_policy,
+            ipsec_policy,
+        }
+    }
+
+    /// Request creation of the site connection.
+    pub fn create(mut self) -> Result<SiteConnection> {
+        self.inner.vpn_service_id = self.vpn_service.into_verified(&self.session)?.into();
+        self.inner.ike_policy_id = self.ike_policy.into_verified(&self.session)?.into();
+        self.inner.ipsec_policy_id = self.ipsec_policy.into_verified(&self.session)?.into();
+        let inner = api::create_site_connection(&self.session, self.inner)?;
+        Ok(SiteConnection::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set administrative status for the site connection."]
+        set_admin_state_up, with_admin_state_up -> admin_state_up: bool
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the site connection."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the dead peer detection settings."]
+        set_dpd, with_dpd -> dpd: optional protocol::DeadPeerDetection
+    }
+
+    creation_inner_field! {
+        #[doc = "Set whether the site connection initiates connections."]
+        set_initiator, with_initiator -> initiator: optional protocol::SiteConnectionInitiator
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the maximum transmission unit."]
+        set_mtu, with_mtu -> mtu: optional u32
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a name for the site connection."]
+        set_name, with_name -> name: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the CIDRs of the remote subnets."]
+        set_peer_cidrs, with_peer_cidrs -> peer_cidrs: Vec<String>
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the router identity of the peer (defaults to the peer address)."]
+        set_peer_id, with_peer_id -> peer_id
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the site connection."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+}
+
+impl IntoFallibleIterator for SiteConnectionQuery {
+    type Item = SiteConnection;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<SiteConnectionQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
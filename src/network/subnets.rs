@@ -23,12 +23,13 @@ use chrono::{DateTime, FixedOffset};
 use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
 
 use super::super::common::{
-    DeletionWaiter, IntoVerified, NetworkRef, Refresh, ResourceIterator, ResourceQuery, SubnetRef,
+    DeletionWaiter, IntoVerified, NetworkRef, Refresh, ResourceIterator, ResourceQuery,
+    SubnetPoolRef, SubnetRef,
 };
 use super::super::session::Session;
 use super::super::utils::Query;
 use super::super::{Error, Result, Sort};
-use super::{api, protocol, Network};
+use super::{api, protocol, Network, SubnetPool};
 
 /// A query to subnet list.
 #[derive(Clone, Debug)]
@@ -53,6 +54,7 @@ pub struct NewSubnet {
     session: Rc<Session>,
     inner: protocol::Subnet,
     network: NetworkRef,
+    subnet_pool: Option<SubnetPoolRef>,
 }
 
 impl Subnet {
@@ -184,6 +186,19 @@ impl Subnet {
         network_id: ref String
     }
 
+    /// Get the subnet pool this subnet was allocated from (if any).
+    pub fn subnet_pool(&self) -> Result<Option<SubnetPool>> {
+        match self.inner.subnet_pool_id {
+            Some(ref id) => SubnetPool::load(self.session.clone(), id).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    transparent_property! {
+        #[doc = "ID of the subnet pool this subnet was allocated from (if any)."]
+        subnet_pool_id: ref Option<String>
+    }
+
     transparent_property! {
         #[doc = "Last update data and time (if available)."]
         updated_at: Option<DateTime<FixedOffset>>
@@ -230,6 +245,8 @@ impl Refresh for Subnet {
     }
 }
 
+taggable!(Subnet, "subnets");
+
 impl SubnetQuery {
     pub(crate) fn new(session: Rc<Session>) -> SubnetQuery {
         SubnetQuery {
@@ -286,6 +303,18 @@ impl SubnetQuery {
         set_gateway_ip, with_gateway_ip -> gateway_ip: net::IpAddr
     }
 
+    /// Filter by IP protocol version.
+    pub fn set_ip_version(&mut self, value: protocol::IpVersion) {
+        self.query.push("ip_version", u8::from(value));
+    }
+
+    /// Filter by IP protocol version.
+    #[inline]
+    pub fn with_ip_version(mut self, value: protocol::IpVersion) -> Self {
+        self.set_ip_version(value);
+        self
+    }
+
     query_filter! {
         #[doc = "Filter by IPv6 address assignment mode."]
         set_ipv6_address_mode, with_ipv6_address_mode ->
@@ -362,11 +391,23 @@ impl ResourceQuery for SubnetQuery {
     }
 
     fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
         let query = self.query.with_marker_and_limit(limit, marker);
-        Ok(api::list_subnets(&self.session, &query)?
-            .into_iter()
-            .map(|item| Subnet::new(self.session.clone(), item))
-            .collect())
+        let (items, next_marker) = api::list_subnets(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| Subnet::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
     }
 
     fn validate(&mut self) -> Result<()> {
@@ -385,12 +426,16 @@ impl NewSubnet {
             session,
             inner: protocol::Subnet::empty(cidr),
             network,
+            subnet_pool: None,
         }
     }
 
     /// Request creation of the subnet.
     pub fn create(mut self) -> Result<Subnet> {
         self.inner.network_id = self.network.into_verified(&self.session)?.into();
+        if let Some(subnet_pool) = self.subnet_pool {
+            self.inner.subnet_pool_id = Some(subnet_pool.into_verified(&self.session)?.into());
+        }
         self.inner.ip_version = match self.inner.cidr {
             ipnet::IpNet::V4(..) => protocol::IpVersion::V4,
             ipnet::IpNet::V6(..) => protocol::IpVersion::V6,
@@ -463,6 +508,27 @@ impl NewSubnet {
         self.set_network(value);
         self
     }
+
+    /// Allocate the subnet from a subnet pool.
+    ///
+    /// The CIDR must be within one of the prefixes of the pool.
+    pub fn set_subnet_pool<P>(&mut self, value: P)
+    where
+        P: Into<SubnetPoolRef>,
+    {
+        self.subnet_pool = Some(value.into());
+    }
+
+    /// Allocate the subnet from a subnet pool.
+    ///
+    /// The CIDR must be within one of the prefixes of the pool.
+    pub fn with_subnet_pool<P>(mut self, value: P) -> Self
+    where
+        P: Into<SubnetPoolRef>,
+    {
+        self.set_subnet_pool(value);
+        self
+    }
 }
 
 impl IntoFallibleIterator for SubnetQuery {
@@ -483,6 +549,12 @@ impl From<Subnet> for SubnetRef {
     }
 }
 
+impl<'a> From<&'a Subnet> for SubnetRef {
+    fn from(value: &'a Subnet) -> SubnetRef {
+        SubnetRef::new_verified(value.inner.id.clone())
+    }
+}
+
 #[cfg(feature = "network")]
 impl IntoVerified for SubnetRef {
     /// Verify this reference and convert to an ID, if possible.
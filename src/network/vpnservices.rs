@@ -0,0 +1,411 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! VPN service management via Network API.
+
+use std::collections::HashSet;
+use std::net;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fallible_iterator::{FallibleIterator, IntoFallibleIterator};
+
+use super::super::common::{
+    DeletionWaiter, IntoVerified, Refresh, ResourceIterator, ResourceQuery, RouterRef,
+    VpnServiceRef,
+};
+use super::super::session::Session;
+use super::super::utils::Query;
+use super::super::{Error, Result};
+use super::{api, protocol, Router};
+
+/// A query to VPN service list.
+#[derive(Clone, Debug)]
+pub struct VpnServiceQuery {
+    session: Rc<Session>,
+    query: Query,
+    can_paginate: bool,
+    router: Option<RouterRef>,
+}
+
+/// Structure representing a single VPN service.
+#[derive(Clone, Debug)]
+pub struct VpnService {
+    session: Rc<Session>,
+    inner: protocol::VpnService,
+    dirty: HashSet<&'static str>,
+}
+
+/// A request to create a VPN service.
+#[derive(Clone, Debug)]
+pub struct NewVpnService {
+    session: Rc<Session>,
+    inner: protocol::VpnService,
+    router: RouterRef,
+}
+
+impl VpnService {
+    /// Create a VPN service object.
+    fn new(session: Rc<Session>, inner: protocol::VpnService) -> VpnService {
+        VpnService {
+            session,
+            inner,
+            dirty: HashSet::new(),
+        }
+    }
+
+    /// Load a VpnService object.
+    pub(crate) fn load<Id: AsRef<str>>(session: Rc<Session>, id: Id) -> Result<VpnService> {
+        let inner = api::get_vpn_service(&session, id)?;
+        Ok(VpnService::new(session, inner))
+    }
+
+    transparent_property! {
+        #[doc = "The administrative state of the VPN service."]
+        admin_state_up: bool
+    }
+
+    update_field! {
+        #[doc = "Set the administrative state of the VPN service."]
+        set_admin_state_up, with_admin_state_up -> admin_state_up: bool
+    }
+
+    transparent_property! {
+        #[doc = "VPN service description."]
+        description: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the description."]
+        set_description, with_description -> description: optional String
+    }
+
+    transparent_property! {
+        #[doc = "External IPv4 address of the VPN service (if available)."]
+        external_v4_ip: Option<net::Ipv4Addr>
+    }
+
+    transparent_property! {
+        #[doc = "External IPv6 address of the VPN service (if available)."]
+        external_v6_ip: Option<net::Ipv6Addr>
+    }
+
+    transparent_property! {
+        #[doc = "Flavor associated with the VPN service."]
+        flavor_id: ref Option<String>
+    }
+
+    transparent_property! {
+        #[doc = "Unique ID."]
+        id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "VPN service name."]
+        name: ref Option<String>
+    }
+
+    update_field! {
+        #[doc = "Update the name."]
+        set_name, with_name -> name: optional String
+    }
+
+    transparent_property! {
+        #[doc = "Project ID."]
+        project_id: ref Option<String>
+    }
+
+    /// Get the router this VPN service belongs to.
+    pub fn router(&self) -> Result<Router> {
+        Router::load(self.session.clone(), &self.inner.router_id)
+    }
+
+    transparent_property! {
+        #[doc = "ID of the router this VPN service belongs to."]
+        router_id: ref String
+    }
+
+    transparent_property! {
+        #[doc = "Status of the VPN service."]
+        status: protocol::VpnStatus
+    }
+
+    transparent_property! {
+        #[doc = "ID of the local subnet (only for legacy VPN services)."]
+        subnet_id: ref Option<String>
+    }
+
+    /// Delete the VPN service.
+    pub fn delete(self) -> Result<DeletionWaiter<VpnService>> {
+        api::delete_vpn_service(&self.session, &self.inner.id)?;
+        Ok(DeletionWaiter::new(
+            self,
+            Duration::new(60, 0),
+            Duration::new(1, 0),
+        ))
+    }
+
+    /// Whether the VPN service is modified.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Save the changes to the VPN service.
+    pub fn save(&mut self) -> Result<()> {
+        let mut update = protocol::VpnServiceUpdate::default();
+        save_fields! {
+            self -> update: admin_state_up
+        };
+        save_option_fields! {
+            self -> update: description name
+        };
+        let inner = api::update_vpn_service(&self.session, self.id(), update)?;
+        self.dirty.clear();
+        self.inner = inner;
+        Ok(())
+    }
+}
+
+impl Refresh for VpnService {
+    /// Refresh the VPN service.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_vpn_service_by_id(&self.session, &self.inner.id)?;
+        self.dirty.clear();
+        Ok(())
+    }
+}
+
+impl VpnServiceQuery {
+    pub(crate) fn new(session: Rc<Session>) -> VpnServiceQuery {
+        VpnServiceQuery {
+            session,
+            query: Query::new(),
+            can_paginate: true,
+            router: None,
+        }
+    }
+
+    /// Add marker to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_marker<T: Into<String>>(mut self, marker: T) -> Self {
+        self.can_paginate = false;
+        self.query.push_str("marker", marker);
+        self
+    }
+
+    /// Add limit to the request.
+    ///
+    /// Using this disables automatic pagination.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.can_paginate = false;
+        self.query.push("limit", limit);
+        self
+    }
+
+    /// Filter by VPN service name (a database regular expression).
+    pub fn with_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.query.push_str("name", value);
+        self
+    }
+
+    /// Filter by router.
+    pub fn set_router<R: Into<RouterRef>>(&mut self, value: R) {
+        self.router = Some(value.into());
+    }
+
+    /// Filter by router.
+    pub fn with_router<R: Into<RouterRef>>(mut self, value: R) -> Self {
+        self.set_router(value);
+        self
+    }
+
+    query_filter! {
+        #[doc = "Filter by VPN service status."]
+        set_status, with_status -> status: protocol::VpnStatus
+    }
+
+    /// Convert this query into an iterator executing the request.
+    ///
+    /// Returns a `FallibleIterator`, which is an iterator with each `next`
+    /// call returning a `Result`.
+    ///
+    /// Note that no requests are done until you start iterating.
+    pub fn into_iter(self) -> ResourceIterator<VpnServiceQuery> {
+        debug!("Fetching VPN services with {:?}", self.query);
+        ResourceIterator::new(self)
+    }
+
+    /// Execute this request and return all results.
+    ///
+    /// A convenience shortcut for `self.into_iter().collect()`.
+    pub fn all(self) -> Result<Vec<VpnService>> {
+        self.into_iter().collect()
+    }
+
+    /// Return one and exactly one result.
+    ///
+    /// Fails with `ResourceNotFound` if the query produces no results and
+    /// with `TooManyItems` if the query produces more than one result.
+    pub fn one(mut self) -> Result<VpnService> {
+        debug!("Fetching one VPN service with {:?}", self.query);
+        if self.can_paginate {
+            // We need only one result. We fetch maximum two to be able
+            // to check if the query yieled more than one result.
+            self.query.push("limit", 2);
+        }
+
+        self.into_iter().one()
+    }
+}
+
+impl ResourceQuery for VpnServiceQuery {
+    type Item = VpnService;
+
+    const DEFAULT_LIMIT: usize = 50;
+
+    fn can_paginate(&self) -> Result<bool> {
+        Ok(self.can_paginate)
+    }
+
+    fn extract_marker(&self, resource: &Self::Item) -> String {
+        resource.id().clone()
+    }
+
+    fn fetch_chunk(&self, limit: Option<usize>, marker: Option<String>) -> Result<Vec<Self::Item>> {
+        Ok(self.fetch_chunk_with_links(limit, marker)?.0)
+    }
+
+    fn fetch_chunk_with_links(
+        &self,
+        limit: Option<usize>,
+        marker: Option<String>,
+    ) -> Result<(Vec<Self::Item>, Option<String>)> {
+        let query = self.query.with_marker_and_limit(limit, marker);
+        let (items, next_marker) = api::list_vpn_services(&self.session, &query)?;
+        Ok((
+            items
+                .into_iter()
+                .map(|item| VpnService::new(self.session.clone(), item))
+                .collect(),
+            next_marker,
+        ))
+    }
+
+    fn validate(&mut self) -> Result<()> {
+        if let Some(router) = self.router.take() {
+            let verified = router.into_verified(&self.session)?;
+            self.query.push_str("router_id", verified);
+        }
+        Ok(())
+    }
+}
+
+impl NewVpnService {
+    /// Start creating a VPN service.
+    pub(crate) fn new(session: Rc<Session>, router: RouterRef) -> NewVpnService {
+        NewVpnService {
+            session,
+            inner: protocol::VpnService {
+                admin_state_up: true,
+                description: None,
+                external_v4_ip: None,
+                external_v6_ip: None,
+                flavor_id: None,
+                id: String::new(),
+                name: None,
+                project_id: None,
+                // Will be replaced in create()
+                router_id: String::new(),
+                // Dummy value, not used when serializing
+                status: protocol::VpnStatus::Active,
+                subnet_id: None,
+            },
+            router,
+        }
+    }
+
+    /// Request creation of the VPN service.
+    pub fn create(mut self) -> Result<VpnService> {
+        self.inner.router_id = self.router.into_verified(&self.session)?.into();
+        let inner = api::create_vpn_service(&self.session, self.inner)?;
+        Ok(VpnService::new(self.session, inner))
+    }
+
+    creation_inner_field! {
+        #[doc = "Set administrative status for the VPN service."]
+        set_admin_state_up, with_admin_state_up -> admin_state_up: bool
+    }
+
+    creation_inner_field! {
+        #[doc = "Set description of the VPN service."]
+        set_description, with_description -> description: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the ID of the flavor associated with the VPN service."]
+        set_flavor_id, with_flavor_id -> flavor_id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a name for the VPN service."]
+        set_name, with_name -> name: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set a project id for the VPN service."]
+        set_project_id, with_project_id -> project_id: optional String
+    }
+
+    creation_inner_field! {
+        #[doc = "Set the local subnet (only for legacy VPN services)."]
+        set_subnet_id, with_subnet_id -> subnet_id: optional String
+    }
+}
+
+impl IntoFallibleIterator for VpnServiceQuery {
+    type Item = VpnService;
+
+    type Error = Error;
+
+    type IntoFallibleIter = ResourceIterator<VpnServiceQuery>;
+
+    fn into_fallible_iter(self) -> Self::IntoFallibleIter {
+        self.into_iter()
+    }
+}
+
+impl From<VpnService> for VpnServiceRef {
+    fn from(value: VpnService) -> VpnServiceRef {
+        VpnServiceRef::new_verified(value.inner.id)
+    }
+}
+
+impl<'a> From<&'a VpnService> for VpnServiceRef {
+    fn from(value: &'a VpnService) -> VpnServiceRef {
+        VpnServiceRef::new_verified(value.inner.id.clone())
+    }
+}
+
+#[cfg(feature = "network")]
+impl IntoVerified for VpnServiceRef {
+    /// Verify this reference and convert to an ID, if possible.
+    fn into_verified(self, session: &Session) -> Result<VpnServiceRef> {
+        Ok(if self.verified {
+            self
+        } else {
+            VpnServiceRef::new_verified(api::get_vpn_service(session, &self.value)?.id)
+        })
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright 2020 Martin Chlumsky <martin.chlumsky@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Object storage accounts.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use super::super::common::Refresh;
+use super::super::session::Session;
+use super::super::Result;
+use super::{api, protocol};
+
+/// Metadata key holding the temporary URL key.
+const TEMP_URL_KEY: &str = "temp-url-key";
+
+/// Capabilities of the object storage service.
+///
+/// The available keys depend on the middleware installed on the cloud.
+#[derive(Clone, Debug)]
+pub struct Capabilities {
+    inner: HashMap<String, serde_json::Value>,
+}
+
+/// Structure representing the object storage account of the current project.
+#[derive(Clone, Debug)]
+pub struct Account {
+    session: Rc<Session>,
+    inner: protocol::Account,
+}
+
+impl Account {
+    /// Load the Account object.
+    pub(crate) fn load(session: Rc<Session>) -> Result<Account> {
+        let inner = api::get_account(&session)?;
+        Ok(Account { session, inner })
+    }
+
+    transparent_property! {
+        #[doc = "Total size of all objects in the account."]
+        bytes: u64
+    }
+
+    /// Fetch capabilities of the object storage service.
+    pub fn capabilities(&self) -> Result<Capabilities> {
+        let inner = api::get_capabilities(&self.session)?;
+        Ok(Capabilities { inner })
+    }
+
+    transparent_property! {
+        #[doc = "Number of containers in the account."]
+        container_count: u64
+    }
+
+    transparent_property! {
+        #[doc = "Account metadata (keys are lowercase)."]
+        metadata: ref HashMap<String, String>
+    }
+
+    transparent_property! {
+        #[doc = "Number of objects in the account."]
+        object_count: u64
+    }
+
+    /// Set the key used to sign temporary URLs for this account.
+    pub fn set_temp_url_key<K: AsRef<str>>(&mut self, key: K) -> Result<()> {
+        api::set_account_metadata(&self.session, TEMP_URL_KEY, key)?;
+        self.refresh()
+    }
+
+    /// The key used to sign temporary URLs (if set).
+    ///
+    /// Only visible to users that are allowed to modify the account.
+    pub fn temp_url_key(&self) -> Option<&String> {
+        self.inner.metadata.get(TEMP_URL_KEY)
+    }
+}
+
+impl Refresh for Account {
+    /// Refresh the account.
+    fn refresh(&mut self) -> Result<()> {
+        self.inner = api::get_account(&self.session)?;
+        Ok(())
+    }
+}
+
+impl Capabilities {
+    /// Options of the given middleware (or `swift` for the core service).
+    pub fn get<S: AsRef<str>>(&self, name: S) -> Option<&serde_json::Value> {
+        self.inner.get(name.as_ref())
+    }
+
+    /// Names of all reported middleware.
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        self.inner.keys()
+    }
+
+    /// Maximum size of a single object in bytes (if reported).
+    pub fn max_file_size(&self) -> Option<u64> {
+        self.get("swift")?.get("max_file_size")?.as_u64()
+    }
+
+    /// Whether bulk deletion is supported.
+    pub fn supports_bulk_delete(&self) -> bool {
+        self.inner.contains_key("bulk_delete")
+    }
+
+    /// Whether static large objects are supported.
+    pub fn supports_slo(&self) -> bool {
+        self.inner.contains_key("slo")
+    }
+
+    /// Whether temporary URLs are supported.
+    pub fn supports_temp_url(&self) -> bool {
+        self.inner.contains_key("tempurl")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use super::Capabilities;
+
+    #[test]
+    fn test_capabilities() {
+        let caps = Capabilities {
+            inner: serde_json::from_str(
+                r#"{"swift": {"max_file_size": 5368709122, "version": "2.25.0"},
+                    "slo": {"max_manifest_segments": 1000},
+                    "tempurl": {"methods": ["GET", "HEAD", "PUT"]}}"#,
+            )
+            .unwrap(),
+        };
+        assert_eq!(caps.max_file_size(), Some(5368709122));
+        assert!(caps.supports_slo());
+        assert!(caps.supports_temp_url());
+        assert!(!caps.supports_bulk_delete());
+        assert_eq!(caps.names().count(), 3);
+    }
+}
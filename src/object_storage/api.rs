@@ -14,20 +14,126 @@
 
 //! Foundation bits exposing the object storage API.
 
+use std::collections::HashMap;
 use std::io;
 
 use futures::stream::Stream;
 use osauth::request::NO_PATH;
 use osauth::services::OBJECT_STORAGE;
 use osauth::sync::{SyncBody, SyncStream, SyncStreamItem};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
 use reqwest::{Method, StatusCode};
 
 use super::super::session::Session;
 use super::super::utils::Query;
-use super::super::Result;
+use super::super::{Error, ErrorKind, Result};
 use super::objects::ObjectHeaders;
 use super::protocol::*;
 
+/// Characters to escape in object paths (everything except unreserved).
+const PATH_ESCAPE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Delete several objects from a container in one request.
+///
+/// Requires the bulk middleware, which limits the number of objects per request.
+pub fn bulk_delete_objects<C, I>(
+    session: &Session,
+    container: C,
+    objects: I,
+) -> Result<BulkDeleteResponse>
+where
+    C: AsRef<str>,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let c_id = container.as_ref();
+    let c_path = utf8_percent_encode(c_id, PATH_ESCAPE).to_string();
+    let body = objects
+        .into_iter()
+        .map(|o_id| {
+            format!(
+                "/{}/{}",
+                c_path,
+                utf8_percent_encode(o_id.as_ref(), PATH_ESCAPE)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    debug!("Bulk deleting objects in container {}", c_id);
+    let req = session
+        .request(OBJECT_STORAGE, Method::POST, NO_PATH, None)?
+        .query(&[("bulk-delete", "")])
+        .header(CONTENT_TYPE, "text/plain")
+        .header(ACCEPT, "application/json")
+        .body(body);
+    let result: BulkDeleteResponse = session.fetch_json(req)?;
+    // Failures of the whole request are only reported in the body.
+    if result.errors.is_empty() && !result.response_status.starts_with('2') {
+        return Err(Error::new(
+            ErrorKind::OperationFailed,
+            format!(
+                "Bulk deletion in container {} failed with {}: {}",
+                c_id, result.response_status, result.response_body
+            ),
+        ));
+    }
+    debug!(
+        "Bulk deleted {} objects in container {}, {} not found, {} failed",
+        result.number_deleted,
+        c_id,
+        result.number_not_found,
+        result.errors.len()
+    );
+    Ok(result)
+}
+
+/// Copy an object on the server side.
+///
+/// Manifests of large objects are copied as they are, without their segments.
+pub fn copy_object<C1, O1, C2, O2>(
+    session: &Session,
+    container: C1,
+    object: O1,
+    dest_container: C2,
+    dest_object: O2,
+) -> Result<Object>
+where
+    C1: AsRef<str>,
+    O1: AsRef<str>,
+    C2: AsRef<str>,
+    O2: AsRef<str>,
+{
+    let c_id = container.as_ref();
+    let o_id = object.as_ref();
+    let dest_c_id = dest_container.as_ref();
+    let dest_o_id = dest_object.as_ref();
+    debug!(
+        "Copying object {} in container {} to {} in container {}",
+        o_id, c_id, dest_o_id, dest_c_id
+    );
+    let destination = format!(
+        "{}/{}",
+        utf8_percent_encode(dest_c_id, PATH_ESCAPE),
+        utf8_percent_encode(dest_o_id, PATH_ESCAPE)
+    );
+    let method = Method::from_bytes(b"COPY").expect("COPY is a valid method");
+    let req = session
+        .request(OBJECT_STORAGE, method, &[c_id, o_id], None)?
+        .query(&[("multipart-manifest", "get")])
+        .header("Destination", destination);
+    let _ = session.send_checked(req)?;
+    debug!(
+        "Successfully copied object {} in container {} to {} in container {}",
+        o_id, c_id, dest_o_id, dest_c_id
+    );
+    get_object(session, dest_c_id, dest_o_id)
+}
+
 /// Create a new container.
 ///
 /// Returns `true` if the container was created, `false` if it existed.
@@ -109,6 +215,54 @@ where
     Ok(())
 }
 
+/// Get account metadata.
+pub fn get_account(session: &Session) -> Result<Account> {
+    trace!("Requesting account information");
+    let resp =
+        session.send_checked(session.request(OBJECT_STORAGE, Method::HEAD, NO_PATH, None)?)?;
+    let result = Account::from_headers(resp.headers())?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
+/// Get capabilities of the object storage service.
+pub fn get_capabilities(session: &Session) -> Result<HashMap<String, serde_json::Value>> {
+    trace!("Requesting object storage capabilities");
+    // The info endpoint lives outside of the versioned API.
+    let mut endpoint = session.get_endpoint(OBJECT_STORAGE, NO_PATH)?;
+    let prefix = endpoint
+        .path_segments()
+        .map(|segments| {
+            segments
+                .take_while(|item| *item != "v1")
+                .map(String::from)
+                .collect::<Vec<_>>()
+        })
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidConfig,
+                format!("Invalid object storage endpoint {}", endpoint),
+            )
+        })?;
+    endpoint.set_query(None);
+    endpoint.set_path("");
+    let _ = endpoint
+        .path_segments_mut()
+        .expect("Endpoint is already known to have a path")
+        .extend(prefix);
+
+    let info_session = Session::new(
+        session
+            .session()
+            .clone()
+            .with_endpoint_override(OBJECT_STORAGE, endpoint),
+    );
+    let result: HashMap<String, serde_json::Value> =
+        info_session.get_json(OBJECT_STORAGE, &["info"], None)?;
+    trace!("Received {:?}", result);
+    Ok(result)
+}
+
 /// Get container metadata.
 pub fn get_container<C>(session: &Session, container: C) -> Result<Container>
 where
@@ -180,3 +334,42 @@ where
     trace!("Received objects: {:?}", root);
     Ok(root)
 }
+
+/// Set a metadata item on the current account.
+pub fn set_account_metadata<K, V>(session: &Session, key: K, value: V) -> Result<()>
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let key = key.as_ref();
+    debug!("Setting account metadata {}", key);
+    let req = session
+        .request(OBJECT_STORAGE, Method::POST, NO_PATH, None)?
+        .header(&format!("X-Account-Meta-{}", key), value.as_ref());
+    let _ = session.send_checked(req)?;
+    debug!("Successfully set account metadata {}", key);
+    Ok(())
+}
+
+/// Set a metadata item on a container.
+pub fn set_container_metadata<C, K, V>(
+    session: &Session,
+    container: C,
+    key: K,
+    value: V,
+) -> Result<()>
+where
+    C: AsRef<str>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let c_id = container.as_ref();
+    let key = key.as_ref();
+    debug!("Setting metadata {} on container {}", key, c_id);
+    let req = session
+        .request(OBJECT_STORAGE, Method::POST, &[c_id], None)?
+        .header(&format!("X-Container-Meta-{}", key), value.as_ref());
+    let _ = session.send_checked(req)?;
+    debug!("Successfully set metadata {} on container {}", key, c_id);
+    Ok(())
+}
@@ -25,6 +25,9 @@ use super::super::{Error, ErrorKind, Result};
 use super::objects::{Object, ObjectQuery};
 use super::{api, protocol};
 
+/// Maximum number of objects per bulk deletion request.
+const BULK_DELETE_BATCH_SIZE: usize = 10000;
+
 /// A query to containers.
 #[derive(Clone, Debug)]
 pub struct ContainerQuery {
@@ -33,6 +36,26 @@ pub struct ContainerQuery {
     can_paginate: bool,
 }
 
+/// An object that could not be deleted by a bulk deletion.
+#[derive(Clone, Debug)]
+pub struct BulkDeleteError {
+    /// Path to the object (`/container/object`, URL-encoded).
+    pub name: String,
+    /// HTTP status reported for the object.
+    pub status: String,
+}
+
+/// Results of a bulk deletion.
+#[derive(Clone, Debug, Default)]
+pub struct BulkDeleteResult {
+    /// Number of deleted objects.
+    pub number_deleted: u64,
+    /// Number of objects that did not exist.
+    pub number_not_found: u64,
+    /// Objects that could not be deleted.
+    pub errors: Vec<BulkDeleteError>,
+}
+
 /// Structure representing a single container.
 #[derive(Clone, Debug)]
 pub struct Container {
@@ -60,6 +83,35 @@ impl Container {
         Ok(Container::new(session, inner))
     }
 
+    /// Delete objects from this container using as few requests as possible.
+    ///
+    /// Requires the bulk middleware to be enabled. Objects are split into
+    /// batches of 10000, the default limit of the middleware.
+    pub fn bulk_delete<I>(&self, object_names: I) -> Result<BulkDeleteResult>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut result = BulkDeleteResult::default();
+        let mut names = object_names.into_iter().peekable();
+        while names.peek().is_some() {
+            let batch = names
+                .by_ref()
+                .take(BULK_DELETE_BATCH_SIZE)
+                .collect::<Vec<_>>();
+            let response = api::bulk_delete_objects(&self.session, &self.inner.name, batch)?;
+            result.number_deleted += response.number_deleted;
+            result.number_not_found += response.number_not_found;
+            result.errors.extend(
+                response
+                    .errors
+                    .into_iter()
+                    .map(|(name, status)| BulkDeleteError { name, status }),
+            );
+        }
+        Ok(result)
+    }
+
     /// Delete the container.
     ///
     /// If `delete_objects` is `true`, all objects inside the container are deleted first.
@@ -109,6 +161,13 @@ impl Container {
         #[doc = "Number of objects in the container."]
         object_count: u64
     }
+
+    /// Set the key used to sign temporary URLs for objects in this container.
+    ///
+    /// It is used in addition to the account key.
+    pub fn set_temp_url_key<K: AsRef<str>>(&self, key: K) -> Result<()> {
+        api::set_container_metadata(&self.session, &self.inner.name, "Temp-URL-Key", key)
+    }
 }
 
 impl Refresh for Container {
@@ -228,5 +287,11 @@ impl From<Container> for ContainerRef {
     }
 }
 
+impl<'a> From<&'a Container> for ContainerRef {
+    fn from(value: &'a Container) -> ContainerRef {
+        ContainerRef::new_verified(value.inner.name.clone())
+    }
+}
+
 #[cfg(feature = "object-storage")]
 impl IntoVerified for ContainerRef {}
@@ -14,10 +14,12 @@
 
 //! Object storage API implementation bits.
 
+mod accounts;
 mod api;
 mod containers;
 mod objects;
 mod protocol;
 
-pub use containers::{Container, ContainerQuery};
+pub use accounts::{Account, Capabilities};
+pub use containers::{BulkDeleteError, BulkDeleteResult, Container, ContainerQuery};
 pub use objects::{NewObject, Object, ObjectQuery};
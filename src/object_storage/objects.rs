@@ -110,12 +110,46 @@ impl Object {
         Ok(Object::new(session, inner, c_name))
     }
 
+    /// Copy the object to the given container and name on the server side.
+    ///
+    /// For large objects only the manifest is copied, the copy refers to the
+    /// same segments.
+    pub fn copy<C, Id>(&self, container: C, name: Id) -> Result<Object>
+    where
+        C: Into<ContainerRef>,
+        Id: AsRef<str>,
+    {
+        let c_name = container.into().to_string();
+        let inner = api::copy_object(&self.session, &self.c_name, &self.inner.name, &c_name, name)?;
+        Ok(Object::new(self.session.clone(), inner, c_name))
+    }
+
     /// Delete the object.
     #[inline]
     pub fn delete(self) -> Result<()> {
         api::delete_object(&self.session, &self.c_name, self.inner.name)
     }
 
+    /// Move the object to the given container and name.
+    ///
+    /// Copies the object and deletes the original. For large objects only the
+    /// manifest is moved, the segments stay in place.
+    pub fn move_to<C, Id>(self, container: C, name: Id) -> Result<Object>
+    where
+        C: Into<ContainerRef>,
+        Id: AsRef<str>,
+    {
+        let c_name = container.into().to_string();
+        if c_name == self.c_name && name.as_ref() == self.inner.name {
+            // Deleting the original would delete the result.
+            return Ok(self);
+        }
+
+        let result = self.copy(c_name, name)?;
+        self.delete()?;
+        Ok(result)
+    }
+
     /// Download the object.
     ///
     /// The object can be read from the resulting reader.
@@ -336,5 +370,11 @@ impl From<Object> for ObjectRef {
     }
 }
 
+impl<'a> From<&'a Object> for ObjectRef {
+    fn from(value: &'a Object) -> ObjectRef {
+        ObjectRef::new_verified(value.inner.name.clone())
+    }
+}
+
 #[cfg(feature = "object-storage")]
 impl IntoVerified for ObjectRef {}
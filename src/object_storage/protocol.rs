@@ -16,12 +16,22 @@
 
 #![allow(missing_docs)]
 
+use std::collections::HashMap;
+
 use reqwest::header::{self, HeaderMap, HeaderName};
 use serde::Deserialize;
 
 use super::super::common::protocol;
 use super::super::{Error, ErrorKind};
 
+#[derive(Debug, Clone)]
+pub struct Account {
+    pub bytes: u64,
+    pub container_count: u64,
+    pub metadata: HashMap<String, String>,
+    pub object_count: u64,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Container {
     pub bytes: u64,
@@ -39,9 +49,57 @@ pub struct Object {
     pub name: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkDeleteResponse {
+    #[serde(rename = "Number Deleted", default)]
+    pub number_deleted: u64,
+    #[serde(rename = "Number Not Found", default)]
+    pub number_not_found: u64,
+    #[serde(rename = "Response Status", default)]
+    pub response_status: String,
+    #[serde(rename = "Response Body", default)]
+    pub response_body: String,
+    #[serde(rename = "Errors", default)]
+    pub errors: Vec<(String, String)>,
+}
+
 static CONTENT_LENGTH: HeaderName = header::CONTENT_LENGTH;
 static CONTENT_TYPE: HeaderName = header::CONTENT_TYPE;
 
+fn get_count_header(value: &HeaderMap, name: &'static str) -> Result<u64, Error> {
+    protocol::get_required_header(value, &HeaderName::from_static(name))?
+        .parse()
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidResponse,
+                format!("{} is not an integer: {}", name, e),
+            )
+        })
+}
+
+impl Account {
+    pub fn from_headers(value: &HeaderMap) -> Result<Account, Error> {
+        let mut metadata = HashMap::new();
+        for (key, item) in value {
+            if let Some(meta_key) = key.as_str().strip_prefix("x-account-meta-") {
+                let item = item.to_str().map_err(|e| {
+                    Error::new(
+                        ErrorKind::InvalidResponse,
+                        format!("{} header is invalid string: {}", key.as_str(), e),
+                    )
+                })?;
+                let _ = metadata.insert(meta_key.to_string(), item.to_string());
+            }
+        }
+        Ok(Account {
+            bytes: get_count_header(value, "x-account-bytes-used")?,
+            container_count: get_count_header(value, "x-account-container-count")?,
+            metadata,
+            object_count: get_count_header(value, "x-account-object-count")?,
+        })
+    }
+}
+
 impl Container {
     pub fn from_headers(name: &str, value: &HeaderMap) -> Result<Container, Error> {
         let bytes_header = HeaderName::from_static("x-container-bytes-used");
@@ -487,7 +487,7 @@ fn test_router_create_update_delete_with_fields() {
     let ports = os.find_ports().with_device_id(router.id()).all();
     assert_eq!(ports.unwrap().len(), 0);
 
-    let port = os.new_port(network.id().as_ref()).create().unwrap();
+    let port = os.new_port(&network).create().unwrap();
     let _ = router.add_router_interface(None, Some(port.id()));
     let ports = os.find_ports().with_device_id(router.id()).all();
     assert_eq!(ports.unwrap().len(), 1);